@@ -0,0 +1,164 @@
+// Allocation profiling: the runner's global allocator counts the allocations, the bytes allocated
+// and the most heap in use at once, for the parse and solve phases of a solver apart. Counting is
+// off until a phase is measured, and then an allocation costs a single atomic load more than it
+// does with the system allocator. The counts are for the whole process, so only solvers that run
+// one at a time, as in the run command, can be measured.
+
+use aoc_common::{Answer, Error, InputSource, Solver};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt;
+use std::sync::atomic::{AtomicBool, AtomicIsize, AtomicU64, Ordering};
+
+pub struct Counting;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+// The heap in use, relative to when counting started; memory allocated before it and freed while
+// counting makes it negative.
+static IN_USE: AtomicIsize = AtomicIsize::new(0);
+static PEAK: AtomicIsize = AtomicIsize::new(0);
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static BYTES: AtomicU64 = AtomicU64::new(0);
+
+impl Counting {
+    fn allocated(size: usize) {
+        if ENABLED.load(Ordering::Relaxed) {
+            let in_use = IN_USE.fetch_add(size as isize, Ordering::Relaxed) + size as isize;
+            PEAK.fetch_max(in_use, Ordering::Relaxed);
+            ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+            BYTES.fetch_add(size as u64, Ordering::Relaxed);
+        }
+    }
+
+    fn freed(size: usize) {
+        if ENABLED.load(Ordering::Relaxed) {
+            IN_USE.fetch_sub(size as isize, Ordering::Relaxed);
+        }
+    }
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            Counting::allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            Counting::allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        Counting::freed(layout.size());
+    }
+
+    // A reallocation counts as freeing the old block and allocating the new one
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() {
+            Counting::freed(layout.size());
+            Counting::allocated(new_size);
+        }
+        new_ptr
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Usage {
+    pub allocations: u64,
+    pub bytes: u64,
+    // The most heap in use at once, beyond what was in use when the phase started
+    pub peak: u64,
+}
+
+impl fmt::Display for Usage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "allocations {:>9}  allocated {:>10}  peak {:>10}",
+            self.allocations,
+            format_bytes(self.bytes),
+            format_bytes(self.peak)
+        )
+    }
+}
+
+pub struct Profile {
+    pub parse: Usage,
+    pub solve: Usage,
+}
+
+// Count the allocations of f.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Usage) {
+    IN_USE.store(0, Ordering::Relaxed);
+    PEAK.store(0, Ordering::Relaxed);
+    ALLOCATIONS.store(0, Ordering::Relaxed);
+    BYTES.store(0, Ordering::Relaxed);
+    ENABLED.store(true, Ordering::Relaxed);
+    let result = f();
+    ENABLED.store(false, Ordering::Relaxed);
+    let usage = Usage {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed),
+        bytes: BYTES.load(Ordering::Relaxed),
+        peak: PEAK.load(Ordering::Relaxed).max(0) as u64,
+    };
+    (result, usage)
+}
+
+// Parse and solve the input, counting the allocations of each phase. Reading the input is not part
+// of either phase.
+pub fn profile_solver(solver: &Solver, source: &InputSource) -> Result<(Answer, Profile), Error> {
+    let input = solver.read_input(source)?;
+    let (parsed, parse) = measure(|| solver.parse_input(&input, source));
+    let parsed = parsed?;
+    let (answer, solve) = measure(|| solver.solve_parsed(parsed.as_ref()));
+    Ok((answer?, Profile { parse, solve }))
+}
+
+// The size in bytes, KiB, MiB or GiB, whichever is shortest.
+fn format_bytes(bytes: u64) -> String {
+    let mut size = bytes as f64;
+    for unit in ["B", "KiB", "MiB"] {
+        if size < 1024.0 {
+            return if unit == "B" {
+                format!("{bytes} B")
+            } else {
+                format!("{size:.1} {unit}")
+            };
+        }
+        size /= 1024.0;
+    }
+    format!("{size:.1} GiB")
+}
+
+#[test]
+fn test_measure() {
+    let kept = vec![0u8; 100];
+    let (sum, usage) = measure(|| {
+        let mut numbers: Vec<u64> = Vec::with_capacity(1000);
+        numbers.extend(0..1000);
+        let small = "x".repeat(16);
+        drop(kept);
+        numbers.iter().sum::<u64>() + small.len() as u64
+    });
+    assert_eq!(sum, 499516);
+    // Other tests allocate and free on other threads meanwhile, so the counts are lower bounds, and
+    // the peak can be anything
+    assert!(usage.allocations >= 2);
+    assert!(usage.bytes >= 8016);
+}
+
+#[test]
+fn test_format_bytes() {
+    assert_eq!(format_bytes(0), "0 B");
+    assert_eq!(format_bytes(1023), "1023 B");
+    assert_eq!(format_bytes(1536), "1.5 KiB");
+    assert_eq!(format_bytes(5 << 20), "5.0 MiB");
+    assert_eq!(format_bytes(3 << 30), "3.0 GiB");
+}
@@ -0,0 +1,282 @@
+// Animating a solver in the terminal: each step of the solution is drawn as a frame, two cells per
+// character with the upper half block and 24-bit ANSI colors, on the alternate screen so that the
+// scrollback is left alone. The viewer can change the speed, pause, step and stop the animation; a
+// stopped animation still solves the rest of the puzzle, so the answer is always the same.
+
+use aoc_common::{Animation, Answer, Grid, InputSource, Picture, Rgb, Solver};
+use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use crossterm::{cursor, execute, queue, terminal};
+use std::fmt::Write as _;
+use std::io::{self, IsTerminal, Stdout, Write};
+use std::time::{Duration, Instant};
+
+const MAX_FPS: f64 = 1000.0;
+const MIN_FPS: f64 = 0.5;
+
+pub struct Options {
+    pub fps: f64,
+    // Stop showing frames after this many
+    pub max_frames: Option<usize>,
+}
+
+// How the animation went, if the solver has one.
+pub struct Played {
+    pub answer: Answer,
+    pub frames: usize,
+    // Whether the viewer stopped it, or it hit the frame cap, before the last frame
+    pub stopped: bool,
+}
+
+pub fn animate(
+    solver: &Solver,
+    source: &InputSource,
+    options: &Options,
+) -> Result<Option<Played>, String> {
+    let input = solver
+        .read_input(source)
+        .map_err(|error| error.to_string())?;
+    let parsed = solver
+        .parse_input(&input, source)
+        .map_err(|error| error.to_string())?;
+    let mut player = Player::new(solver, options);
+    let mut show = |picture: Picture| player.show(&picture);
+    let answer = (solver.animate)(parsed.as_ref(), &mut Animation::new(&mut show));
+    let (frames, stopped, error) = player.finish();
+    // Solvers without an animation don't need a terminal
+    if answer.is_some() && !io::stdout().is_terminal() {
+        return Err("Animating needs a terminal, but the output is not one".to_string());
+    }
+    if let Some(error) = error {
+        return Err(format!("Cannot draw the animation: {error}"));
+    }
+    Ok(answer.map(|answer| Played {
+        answer,
+        frames,
+        stopped,
+    }))
+}
+
+struct Player<'a> {
+    solver: &'a Solver,
+    fps: f64,
+    max_frames: Option<usize>,
+    paused: bool,
+    frames: usize,
+    stopped: bool,
+    next_frame: Instant,
+    // The terminal is only taken over once the first frame arrives, so that solvers without an
+    // animation leave it alone.
+    screen: Option<Screen>,
+    error: Option<io::Error>,
+}
+
+impl<'a> Player<'a> {
+    fn new(solver: &'a Solver, options: &Options) -> Player<'a> {
+        Player {
+            solver,
+            fps: options.fps.clamp(MIN_FPS, MAX_FPS),
+            max_frames: options.max_frames,
+            paused: false,
+            frames: 0,
+            stopped: false,
+            next_frame: Instant::now(),
+            screen: None,
+            error: None,
+        }
+    }
+
+    // Returns whether to show more frames. Without a terminal, or once the frame cap is reached, it
+    // shows none.
+    fn show(&mut self, picture: &Picture) -> bool {
+        if self.capped() || !io::stdout().is_terminal() {
+            self.stopped = true;
+            return false;
+        }
+        if let Err(error) = self.try_show(picture) {
+            self.error = Some(error);
+            self.stopped = true;
+        }
+        self.stopped |= self.capped();
+        !self.stopped
+    }
+
+    fn capped(&self) -> bool {
+        self.max_frames.is_some_and(|max| self.frames >= max)
+    }
+
+    fn try_show(&mut self, picture: &Picture) -> io::Result<()> {
+        if self.screen.is_none() {
+            self.screen = Some(Screen::enter()?);
+        }
+        self.frames += 1;
+        let colors = picture.colors(&[]).map_err(io::Error::other)?;
+        self.screen.as_mut().unwrap().draw(&colors)?;
+        self.wait()
+    }
+
+    // Wait until it is time for the next frame, handling the keys pressed meanwhile.
+    fn wait(&mut self) -> io::Result<()> {
+        loop {
+            self.draw_status()?;
+            let now = Instant::now();
+            if !self.paused && now >= self.next_frame {
+                break;
+            }
+            let timeout = if self.paused {
+                Duration::from_secs(1)
+            } else {
+                self.next_frame - now
+            };
+            if !event::poll(timeout)? {
+                continue;
+            }
+            let Event::Key(key) = event::read()? else {
+                continue;
+            };
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+            match key.code {
+                KeyCode::Char(' ') => self.paused = !self.paused,
+                KeyCode::Char('n') if self.paused => break,
+                KeyCode::Char('+') | KeyCode::Char('=') => self.fps = (self.fps * 2.0).min(MAX_FPS),
+                KeyCode::Char('-') => self.fps = (self.fps / 2.0).max(MIN_FPS),
+                KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    self.stopped = true;
+                    break;
+                }
+                KeyCode::Char('q') | KeyCode::Esc => {
+                    self.stopped = true;
+                    break;
+                }
+                _ => (),
+            }
+        }
+        self.next_frame = Instant::now() + Duration::from_secs_f64(1.0 / self.fps);
+        Ok(())
+    }
+
+    fn draw_status(&mut self) -> io::Result<()> {
+        let state = if self.paused {
+            "paused, n: step"
+        } else {
+            "playing"
+        };
+        let status = format!(
+            "Day {} part {}  frame {}  {} fps  {state}  space: pause  +/-: speed  q: stop",
+            self.solver.day, self.solver.part, self.frames, self.fps
+        );
+        self.screen.as_mut().unwrap().draw_status(&status)
+    }
+
+    // Give the terminal back. Returns the number of frames shown, whether the animation was
+    // stopped early, and the error that stopped it, if any.
+    fn finish(self) -> (usize, bool, Option<io::Error>) {
+        drop(self.screen);
+        (self.frames, self.stopped, self.error)
+    }
+}
+
+// The terminal in raw mode on the alternate screen, until dropped.
+struct Screen {
+    stdout: Stdout,
+    cropped: bool,
+}
+
+impl Screen {
+    fn enter() -> io::Result<Screen> {
+        let mut stdout = io::stdout();
+        terminal::enable_raw_mode()?;
+        execute!(
+            stdout,
+            terminal::EnterAlternateScreen,
+            terminal::Clear(terminal::ClearType::All),
+            cursor::Hide
+        )?;
+        Ok(Screen {
+            stdout,
+            cropped: false,
+        })
+    }
+
+    // Draw the cells that fit above the status line, two rows of cells per line of text.
+    fn draw(&mut self, colors: &Grid<Rgb>) -> io::Result<()> {
+        let (columns, rows) = terminal::size()?;
+        let width = colors.width().min(columns as usize);
+        let height = colors.height().min(2 * (rows as usize).saturating_sub(1));
+        self.cropped = width < colors.width() || height < colors.height();
+        let mut text = String::new();
+        for y in (0..height).step_by(2) {
+            let _ = write!(text, "\x1b[{};1H", y / 2 + 1);
+            for x in 0..width {
+                let Rgb(r, g, b) = colors[(x, y)];
+                let _ = write!(text, "\x1b[38;2;{r};{g};{b}m");
+                if y + 1 < height {
+                    let Rgb(r, g, b) = colors[(x, y + 1)];
+                    let _ = write!(text, "\x1b[48;2;{r};{g};{b}m");
+                }
+                text.push('▀');
+                text.push_str("\x1b[0m");
+            }
+        }
+        self.stdout.write_all(text.as_bytes())?;
+        self.stdout.flush()
+    }
+
+    fn draw_status(&mut self, status: &str) -> io::Result<()> {
+        let (columns, rows) = terminal::size()?;
+        let cropped = if self.cropped { "  (cropped)" } else { "" };
+        let status: String = format!("{status}{cropped}")
+            .chars()
+            .take(columns as usize)
+            .collect();
+        queue!(
+            self.stdout,
+            cursor::MoveTo(0, rows.saturating_sub(1)),
+            terminal::Clear(terminal::ClearType::CurrentLine)
+        )?;
+        self.stdout.write_all(status.as_bytes())?;
+        self.stdout.flush()
+    }
+}
+
+impl Drop for Screen {
+    fn drop(&mut self) {
+        let _ = execute!(self.stdout, cursor::Show, terminal::LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+// Stopping an animation early must not change the answer.
+#[test]
+fn test_animations_keep_answers() {
+    let mut animated = 0;
+    for solver in crate::registry::SOLVERS {
+        // The first example of the part, since not every example fits both parts
+        let dir = std::path::Path::new(solver.dir);
+        let Some(example) = aoc_common::read_answers(dir, solver.part)
+            .unwrap()
+            .into_iter()
+            .find(|expected| expected.input.starts_with("example_input"))
+        else {
+            continue;
+        };
+        let source = InputSource::Path(dir.join(example.input));
+        let input = solver.read_input(&source).unwrap();
+        let parsed = solver.parse_input(&input, &source).unwrap();
+        let expected = (solver.solve)(parsed.as_ref());
+        for frames in [1, 3, usize::MAX] {
+            let mut shown = 0;
+            let mut show = |_picture: Picture| {
+                shown += 1;
+                shown < frames
+            };
+            let answer = (solver.animate)(parsed.as_ref(), &mut Animation::new(&mut show));
+            if let Some(answer) = answer {
+                assert_eq!(answer, expected, "Day {} part {}", solver.day, solver.part);
+                animated += 1;
+            }
+        }
+    }
+    assert_eq!(animated, 3 * 4);
+}
@@ -0,0 +1,175 @@
+// Batch mode: solve a day for every input file in a directory, e.g. the puzzle inputs of many
+// people, on a pool of threads. Each file gets its answer and time, and the files on which the
+// solver failed are listed apart, by how it failed.
+
+use crate::check::{Outcome, Status, align, answer_cell, check_solver, in_parallel};
+use aoc_common::{InputSource, Solver};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+pub struct FileOutcome {
+    pub file: PathBuf,
+    pub outcome: Outcome,
+}
+
+// The files in the directory, in order of name, skipping hidden files and subdirectories.
+pub fn input_files(dir: &Path) -> Result<Vec<PathBuf>, String> {
+    let entries =
+        fs::read_dir(dir).map_err(|err| format!("Cannot read {}: {err}", dir.display()))?;
+    let mut files = Vec::new();
+    for entry in entries {
+        let entry = entry.map_err(|err| format!("Cannot read {}: {err}", dir.display()))?;
+        let hidden = entry.file_name().to_string_lossy().starts_with('.');
+        if entry.path().is_file() && !hidden {
+            files.push(entry.path());
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+// Solve every file with every solver on jobs threads. The outcomes are by file, then by solver.
+pub fn batch(
+    solvers: &[&'static Solver],
+    files: &[PathBuf],
+    jobs: usize,
+    timeout: Duration,
+) -> Vec<FileOutcome> {
+    let runs: Vec<(&PathBuf, &'static Solver)> = files
+        .iter()
+        .flat_map(|file| solvers.iter().map(move |&solver| (file, solver)))
+        .collect();
+    in_parallel(&runs, jobs, |&(file, solver)| {
+        let source = InputSource::Path(file.clone());
+        FileOutcome {
+            file: file.clone(),
+            outcome: check_solver(solver, &source, None, timeout),
+        }
+    })
+}
+
+// There is no expected answer to compare with, so every answer is fine.
+fn describe(status: &Status) -> String {
+    match status {
+        Status::Unchecked => "ok".to_string(),
+        status => status.describe(),
+    }
+}
+
+const FAILURES: [&str; 5] = [
+    "Could not be read or parsed",
+    "Input assumption failed",
+    "Overflowed",
+    "Panicked",
+    "Timed out",
+];
+
+// Which of the FAILURES the status is, if any.
+fn failure(status: &Status) -> Option<&'static str> {
+    match status {
+        Status::Error(_) => Some(FAILURES[0]),
+        Status::AssumptionFailed(_) => Some(FAILURES[1]),
+        Status::Overflowed(_) => Some(FAILURES[2]),
+        Status::Panicked(_) => Some(FAILURES[3]),
+        Status::TimedOut => Some(FAILURES[4]),
+        Status::Ok | Status::Wrong { .. } | Status::Unchecked => None,
+    }
+}
+
+// A table with a row per file and part, followed by how many runs had each status, and the files
+// for which there was no answer, grouped by why.
+pub fn report(outcomes: &[FileOutcome]) -> String {
+    let rows = outcomes.iter().map(|FileOutcome { file, outcome }| {
+        [
+            file.display().to_string(),
+            outcome.solver.part.to_string(),
+            answer_cell(&outcome.answer),
+            format!("{:.1?}", outcome.elapsed),
+            describe(&outcome.status),
+        ]
+    });
+    let mut report = align(
+        ["File", "Part", "Answer", "Time", "Status"],
+        rows,
+        &[false, false, true, true],
+    );
+    let answered = outcomes
+        .iter()
+        .filter(|file_outcome| file_outcome.outcome.answer.is_some())
+        .count();
+    let mut counts = vec![format!("{answered} answered")];
+    for title in FAILURES {
+        let failed: Vec<&FileOutcome> = outcomes
+            .iter()
+            .filter(|file_outcome| failure(&file_outcome.outcome.status) == Some(title))
+            .collect();
+        counts.push(format!("{} {}", failed.len(), title.to_lowercase()));
+        if failed.is_empty() {
+            continue;
+        }
+        report += &format!("\n{title}:\n");
+        for FileOutcome { file, outcome } in failed {
+            report += &format!(
+                "  {} part {}: {}\n",
+                file.display(),
+                outcome.solver.part,
+                describe(&outcome.status)
+            );
+        }
+    }
+    report += &format!("\n{}\n", counts.join(", "));
+    report
+}
+
+#[test]
+fn test_batch() {
+    use aoc_common::{Answer, ParseError, Solution};
+
+    struct Broken;
+
+    impl Solution for Broken {
+        type Input = ();
+
+        fn parse(_input: &str) -> Result<(), ParseError> {
+            Ok(())
+        }
+
+        // Panics on any input
+        fn part_b(_input: &()) -> Option<Answer> {
+            panic!("broken")
+        }
+    }
+
+    static BROKEN: Solver = Solver::new::<Broken>(8, 'b', "Broken", "");
+    let dir = std::env::temp_dir().join(format!("aoc_batch_{}", std::process::id()));
+    fs::create_dir_all(dir.join("subdirectory")).unwrap();
+    let day_8 = Path::new(env!("CARGO_MANIFEST_DIR")).join("../day_8");
+    fs::copy(day_8.join("example_input_3"), dir.join("1_example")).unwrap();
+    // 11A reaches 11Z after 1 step, but then 22Z after 1 more
+    fs::write(
+        dir.join("2_no_cycle"),
+        "L\n\n11A = (11Z, XXX)\n11Z = (22Z, XXX)\n22Z = (22Z, XXX)\nXXX = (XXX, XXX)\n",
+    )
+    .unwrap();
+    fs::write(dir.join("3_empty"), "").unwrap();
+    fs::write(dir.join(".hidden"), "").unwrap();
+    let files = input_files(&dir).unwrap();
+    assert_eq!(files.len(), 3);
+    let mut outcomes = batch(&[&day_8::SOLVER_B], &files, 2, Duration::from_secs(10));
+    outcomes.extend(batch(&[&BROKEN], &files[..1], 1, Duration::from_secs(10)));
+    fs::remove_dir_all(&dir).unwrap();
+    let statuses: Vec<&Status> = outcomes.iter().map(|o| &o.outcome.status).collect();
+    assert_eq!(statuses[0], &Status::Unchecked);
+    assert_eq!(
+        outcomes[0].outcome.answer.as_ref().unwrap().to_string(),
+        "6"
+    );
+    assert!(matches!(statuses[1], Status::AssumptionFailed(_)));
+    assert!(matches!(statuses[2], Status::Error(_)));
+    assert_eq!(statuses[3], &Status::Panicked("broken".to_string()));
+    let report = report(&outcomes);
+    assert!(report.ends_with(
+        "1 answered, 1 could not be read or parsed, 1 input assumption failed, 0 overflowed, 1 panicked, 0 timed out\n"
+    ));
+}
@@ -0,0 +1,214 @@
+// Benchmark mode: parse and solve the input of a solver a number of times, timing both phases
+// separately, and compare the timings against a baseline saved by an earlier run.
+
+use aoc_common::{Answer, Error, InputSource, Solver};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::hint::black_box;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+// Changes in the median smaller than this are noise, however large they are in percent.
+const NOISE_FLOOR: Duration = Duration::from_micros(100);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PhaseTiming {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl PhaseTiming {
+    fn from_samples(mut samples: Vec<Duration>) -> PhaseTiming {
+        samples.sort();
+        PhaseTiming {
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+impl fmt::Display for PhaseTiming {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "min {:>10.1?}  median {:>10.1?}  max {:>10.1?}",
+            self.min, self.median, self.max
+        )
+    }
+}
+
+pub struct Timing {
+    // The hash of the input, since timings are only comparable for the same input
+    pub input_hash: String,
+    pub parse: PhaseTiming,
+    pub solve: PhaseTiming,
+}
+
+// Parse and solve the input repeats times. The input is read only once, so reading it from disk
+// (or stdin) is not part of the timing.
+pub fn time_solver(
+    solver: &Solver,
+    source: &InputSource,
+    repeats: u32,
+) -> Result<(Answer, Timing), Error> {
+    let input = solver.read_input(source)?;
+    let mut parse_samples = Vec::new();
+    let mut solve_samples = Vec::new();
+    let mut answer = None;
+    for _ in 0..repeats {
+        let start = Instant::now();
+        let parsed = solver.parse_input(black_box(&input), source)?;
+        parse_samples.push(start.elapsed());
+        let start = Instant::now();
+        answer = Some(black_box(solver.solve_parsed(parsed.as_ref())?));
+        solve_samples.push(start.elapsed());
+    }
+    let timing = Timing {
+        input_hash: crate::history::hash(&input),
+        parse: PhaseTiming::from_samples(parse_samples),
+        solve: PhaseTiming::from_samples(solve_samples),
+    };
+    Ok((answer.expect("No repeats"), timing))
+}
+
+// The median parse and solve times of each day and part, saved as one line per part:
+// <day> <part> <input hash> <parse ns> <solve ns>
+#[derive(Debug, Default, PartialEq)]
+pub struct Baseline {
+    medians: BTreeMap<(u32, char, String), (Duration, Duration)>,
+}
+
+impl Baseline {
+    pub fn load(path: &Path) -> Result<Baseline, String> {
+        let text = fs::read_to_string(path)
+            .map_err(|err| format!("Could not read baseline {}: {err}", path.display()))?;
+        Baseline::parse(&text)
+            .map_err(|line| format!("Invalid line in baseline {}: '{line}'", path.display()))
+    }
+
+    // Load the baseline if there is one yet, so that saving only replaces the parts that were run.
+    pub fn load_or_default(path: &Path) -> Result<Baseline, String> {
+        if path.exists() {
+            Baseline::load(path)
+        } else {
+            Ok(Baseline::default())
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        fs::write(path, self.to_string())
+            .map_err(|err| format!("Could not write baseline {}: {err}", path.display()))
+    }
+
+    // Returns the offending line if the text is not a valid baseline.
+    fn parse(text: &str) -> Result<Baseline, &str> {
+        let mut medians = BTreeMap::new();
+        for line in text.lines().filter(|line| !line.trim().is_empty()) {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let [day, part, input_hash, parse_ns, solve_ns] = fields[..] else {
+                return Err(line);
+            };
+            let parse_number = |text: &str| text.parse::<u64>().map_err(|_| line);
+            let part = part.parse::<char>().map_err(|_| line)?;
+            let day = day.parse::<u32>().map_err(|_| line)?;
+            let parse = Duration::from_nanos(parse_number(parse_ns)?);
+            let solve = Duration::from_nanos(parse_number(solve_ns)?);
+            medians.insert((day, part, input_hash.to_string()), (parse, solve));
+        }
+        Ok(Baseline { medians })
+    }
+
+    pub fn insert(&mut self, solver: &Solver, timing: &Timing) {
+        self.medians.insert(
+            (solver.day, solver.part, timing.input_hash.clone()),
+            (timing.parse.median, timing.solve.median),
+        );
+    }
+
+    // Describe each phase of the solver that got slower than the baseline by more than threshold
+    // percent. Parts that are not in the baseline for the same input can't regress.
+    pub fn regressions(&self, solver: &Solver, timing: &Timing, threshold: f64) -> Vec<String> {
+        let key = (solver.day, solver.part, timing.input_hash.clone());
+        let Some(&(parse, solve)) = self.medians.get(&key) else {
+            return Vec::new();
+        };
+        [
+            ("parse", parse, timing.parse.median),
+            ("solve", solve, timing.solve.median),
+        ]
+        .into_iter()
+        .filter(|&(_, before, after)| is_regression(before, after, threshold))
+        .map(|(phase, before, after)| {
+            let percent = (after.as_secs_f64() / before.as_secs_f64() - 1.0) * 100.0;
+            format!(
+                "{phase} regressed: median {after:.1?} vs baseline {before:.1?} (+{percent:.0}%)"
+            )
+        })
+        .collect()
+    }
+}
+
+impl fmt::Display for Baseline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for ((day, part, input_hash), (parse, solve)) in &self.medians {
+            writeln!(
+                f,
+                "{day} {part} {input_hash} {} {}",
+                parse.as_nanos(),
+                solve.as_nanos()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+fn is_regression(before: Duration, after: Duration, threshold: f64) -> bool {
+    after > before + NOISE_FLOOR
+        && after.as_secs_f64() > before.as_secs_f64() * (1.0 + threshold / 100.0)
+}
+
+#[test]
+fn test_phase_timing() {
+    let samples = [5, 1, 4, 2, 3].map(Duration::from_millis).to_vec();
+    let timing = PhaseTiming::from_samples(samples);
+    assert_eq!(timing.min, Duration::from_millis(1));
+    assert_eq!(timing.median, Duration::from_millis(3));
+    assert_eq!(timing.max, Duration::from_millis(5));
+}
+
+#[test]
+fn test_baseline() {
+    let text = "1 a 52bed28f1d7ccf0f 1500 20000\n\
+                17 b 52bed28f1d7ccf0f 30000 400000000\n\
+                17 b 9e0f3a4c1d2b5a67 900 12000\n";
+    let baseline = Baseline::parse(text).unwrap();
+    assert_eq!(baseline.to_string(), text);
+    assert_eq!(Baseline::parse("1 a 1500 20000\n"), Err("1 a 1500 20000"));
+    let ms = Duration::from_millis;
+    assert!(is_regression(ms(400), ms(500), 10.0));
+    assert!(!is_regression(ms(400), ms(420), 10.0));
+    // Only timings of the same input are compared
+    let slow = PhaseTiming {
+        min: ms(900),
+        median: ms(900),
+        max: ms(900),
+    };
+    let timing = |input_hash: &str| Timing {
+        input_hash: input_hash.to_string(),
+        parse: slow,
+        solve: slow,
+    };
+    let regressions =
+        |input_hash| baseline.regressions(&day_17::SOLVER_B, &timing(input_hash), 10.0);
+    assert_eq!(regressions("52bed28f1d7ccf0f").len(), 2);
+    assert!(regressions("0123456789abcdef").is_empty());
+    // Too small to tell from noise
+    assert!(!is_regression(
+        Duration::from_micros(10),
+        Duration::from_micros(50),
+        10.0
+    ));
+}
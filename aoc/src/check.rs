@@ -0,0 +1,274 @@
+// Check mode: solve the puzzle input of every day and part at the same time on a pool of threads,
+// and compare each answer with the answers manifest of its day. A solver that panics or runs out of
+// time only fails its own row of the table.
+
+use aoc_common::{ASSUMPTION_FAILED, Answer, Error, InputSource, Solver};
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, PartialEq)]
+pub enum Status {
+    Ok,
+    Wrong { expected: String },
+    // The manifest has no answer for the puzzle input
+    Unchecked,
+    Error(String),
+    // The solver relies on a property that the input does not have, see aoc_common::assume
+    AssumptionFailed(String),
+    // An arithmetic overflow, in a build with overflow checks
+    Overflowed(String),
+    Panicked(String),
+    TimedOut,
+}
+
+pub struct Outcome {
+    pub solver: &'static Solver,
+    pub answer: Option<Answer>,
+    pub elapsed: Duration,
+    pub status: Status,
+}
+
+// Check the solvers on jobs threads. The outcomes are in the same order as the solvers.
+pub fn check(solvers: &[&'static Solver], jobs: usize, timeout: Duration) -> Vec<Outcome> {
+    in_parallel(solvers, jobs, |&solver| {
+        match solver.expected_puzzle_answer() {
+            Ok(expected) => check_solver(solver, &InputSource::Puzzle, expected, timeout),
+            Err(message) => Outcome {
+                solver,
+                answer: None,
+                elapsed: Duration::ZERO,
+                status: Status::Error(message),
+            },
+        }
+    })
+}
+
+// Apply work to each item on jobs threads, and return the results in the same order as the items.
+// Panics are reported in the results instead of on stderr.
+pub fn in_parallel<T: Sync, R: Send>(
+    items: &[T],
+    jobs: usize,
+    work: impl Fn(&T) -> R + Sync,
+) -> Vec<R> {
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new(Vec::new());
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| ()));
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, items.len().max(1)) {
+            scope.spawn(|| {
+                loop {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(item) = items.get(index) else {
+                        break;
+                    };
+                    let result = work(item);
+                    results.lock().unwrap().push((index, result));
+                }
+            });
+        }
+    });
+    panic::set_hook(hook);
+    let mut results = results.into_inner().unwrap();
+    results.sort_by_key(|&(index, _)| index);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+// The solver runs on a thread of its own, so that it can be given up on when it takes too long.
+// Threads cannot be stopped, so a solver that timed out keeps running until the runner exits.
+pub fn check_solver(
+    solver: &'static Solver,
+    source: &InputSource,
+    expected: Option<String>,
+    timeout: Duration,
+) -> Outcome {
+    let (sender, receiver) = mpsc::channel();
+    let source = source.clone();
+    let start = Instant::now();
+    thread::spawn(move || {
+        let result = panic::catch_unwind(AssertUnwindSafe(|| solver.solve_input(&source)));
+        let _ = sender.send(result);
+    });
+    let result = receiver.recv_timeout(timeout);
+    let elapsed = start.elapsed();
+    let (answer, status) = match result {
+        Ok(Ok(Ok(answer))) => {
+            let status = match expected {
+                Some(expected) if expected == answer.to_string() => Status::Ok,
+                Some(expected) => Status::Wrong { expected },
+                None => Status::Unchecked,
+            };
+            (Some(answer), status)
+        }
+        Ok(Ok(Err(Error::Overflow(message)))) => (None, Status::Overflowed(message)),
+        Ok(Ok(Err(error))) => {
+            let message = error.to_string();
+            let first_line = message.lines().next().unwrap_or_default();
+            (None, Status::Error(first_line.to_string()))
+        }
+        Ok(Err(payload)) => {
+            let message = panic_message(payload.as_ref());
+            match message.strip_prefix(ASSUMPTION_FAILED) {
+                Some(assumption) => {
+                    let assumption = assumption.trim_start_matches(": ").to_string();
+                    (None, Status::AssumptionFailed(assumption))
+                }
+                None => (None, Status::Panicked(message)),
+            }
+        }
+        Err(RecvTimeoutError::Timeout) => (None, Status::TimedOut),
+        Err(RecvTimeoutError::Disconnected) => (None, Status::Panicked(String::new())),
+    };
+    Outcome {
+        solver,
+        answer,
+        elapsed,
+        status,
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        String::new()
+    }
+}
+
+impl Status {
+    pub fn describe(&self) -> String {
+        match self {
+            Status::Ok => "ok".to_string(),
+            Status::Wrong { expected } => format!("wrong, expected {expected}"),
+            Status::Unchecked => "unchecked, no answer in manifest".to_string(),
+            Status::Error(message) => format!("error: {message}"),
+            Status::AssumptionFailed(assumption) => format!("assumption failed: {assumption}"),
+            Status::Overflowed(message) => format!("overflowed: {message}"),
+            Status::Panicked(message) if message.is_empty() => "panicked".to_string(),
+            Status::Panicked(message) => format!("panicked: {message}"),
+            Status::TimedOut => "timed out".to_string(),
+        }
+    }
+}
+
+// A table with a row per outcome, followed by how many of them had each status.
+pub fn table(outcomes: &[Outcome]) -> String {
+    let rows = outcomes.iter().map(|outcome| {
+        [
+            outcome.solver.day.to_string(),
+            outcome.solver.part.to_string(),
+            answer_cell(&outcome.answer),
+            format!("{:.1?}", outcome.elapsed),
+            outcome.status.describe(),
+        ]
+    });
+    let mut table = align(
+        ["Day", "Part", "Answer", "Time", "Status"],
+        rows,
+        &[true, false, true, true],
+    );
+    let count = |matches: fn(&Status) -> bool| {
+        outcomes
+            .iter()
+            .filter(|outcome| matches(&outcome.status))
+            .count()
+    };
+    table += &format!(
+        "{} ok, {} wrong, {} unchecked, {} failed, {} assumption failed, {} overflowed, {} panicked, {} timed out\n",
+        count(|status| *status == Status::Ok),
+        count(|status| matches!(status, Status::Wrong { .. })),
+        count(|status| *status == Status::Unchecked),
+        count(|status| matches!(status, Status::Error(_))),
+        count(|status| matches!(status, Status::AssumptionFailed(_))),
+        count(|status| matches!(status, Status::Overflowed(_))),
+        count(|status| matches!(status, Status::Panicked(_))),
+        count(|status| *status == Status::TimedOut),
+    );
+    table
+}
+
+pub fn answer_cell(answer: &Option<Answer>) -> String {
+    answer.as_ref().map_or("-".to_string(), Answer::to_string)
+}
+
+// Lay out the header and rows in columns. The last column is not padded, and the others are
+// aligned to the right or to the left.
+pub fn align<const N: usize>(
+    header: [&str; N],
+    rows: impl Iterator<Item = [String; N]>,
+    align_right: &[bool],
+) -> String {
+    let mut rows: Vec<[String; N]> = rows.collect();
+    rows.insert(0, header.map(String::from));
+    let widths: Vec<usize> = (0..N)
+        .map(|column| rows.iter().map(|row| row[column].len()).max().unwrap())
+        .collect();
+    let mut table = String::new();
+    for row in &rows {
+        let mut line = String::new();
+        for (column, cell) in row.iter().enumerate() {
+            let width = widths[column];
+            match align_right.get(column) {
+                Some(true) => line += &format!("{cell:>width$}  "),
+                Some(false) => line += &format!("{cell:<width$}  "),
+                None => line += cell,
+            }
+        }
+        table += line.trim_end();
+        table.push('\n');
+    }
+    table
+}
+
+#[test]
+fn test_check_solver_statuses() {
+    use aoc_common::{ParseError, Solution};
+
+    struct Sleepy;
+
+    impl Solution for Sleepy {
+        type Input = u64;
+
+        fn parse(input: &str) -> Result<u64, ParseError> {
+            aoc_common::parse_number(input.trim())
+        }
+
+        // Sleeps for the input in milliseconds, and panics if that is 0.
+        fn part_a(millis: &u64) -> Option<Answer> {
+            assert!(*millis != 0, "no sleep");
+            thread::sleep(Duration::from_millis(*millis));
+            Some((*millis as i64).into())
+        }
+    }
+
+    static SOLVER: Solver = Solver::new::<Sleepy>(1, 'a', "Sleepy", "");
+    let path = std::env::temp_dir().join(format!("aoc_check_{}", std::process::id()));
+    let status = |input: &str, expected: Option<&str>| {
+        std::fs::write(&path, input).unwrap();
+        let source = InputSource::Path(path.clone());
+        let expected = expected.map(String::from);
+        check_solver(&SOLVER, &source, expected, Duration::from_millis(200)).status
+    };
+    assert_eq!(status("1", Some("1")), Status::Ok);
+    assert_eq!(
+        status("2", Some("1")),
+        Status::Wrong {
+            expected: "1".to_string()
+        }
+    );
+    assert_eq!(status("1", None), Status::Unchecked);
+    assert!(matches!(status("x", Some("1")), Status::Error(_)));
+    assert_eq!(
+        status("0", Some("1")),
+        Status::Panicked("no sleep".to_string())
+    );
+    assert_eq!(status("5000", Some("5000")), Status::TimedOut);
+    std::fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,217 @@
+// Differential testing: some days compute the same quantity with two algorithms, a simple one that
+// is obviously right but slow, and a clever one. Both are run on many small random inputs, and any
+// input on which they disagree is a bug in one of them. Inputs are tried from the smallest size up,
+// so the input that is reported is the smallest one found, which is the easiest to debug.
+
+use crate::random::Rng;
+use std::panic::{self, AssertUnwindSafe};
+
+pub struct Comparison {
+    pub name: &'static str,
+    // A random puzzle input, where the size is e.g. the length of a record or the number of steps
+    generate: fn(&mut Rng, usize) -> String,
+    // The two algorithms, with their names, each giving its answer for the input
+    left: (&'static str, fn(&str) -> String),
+    right: (&'static str, fn(&str) -> String),
+}
+
+pub const COMPARISONS: &[Comparison] = &[
+    Comparison {
+        name: "day 6 ways to win",
+        generate: race,
+        left: ("brute force", |input| {
+            let race = day_6::parse(input).unwrap().kerned;
+            day_6::part_b::brute_force_solve(race.time, race.distance).to_string()
+        }),
+        right: ("math", |input| {
+            let race = day_6::parse(input).unwrap().kerned;
+            day_6::part_b::math_solve(race.time, race.distance).to_string()
+        }),
+    },
+    Comparison {
+        name: "day 12 arrangements",
+        generate: spring_record,
+        left: ("exhaustive (part a)", |input| {
+            day_12::part_a::nr_possible_arrangements(&day_12::parse(input).unwrap()[0]).to_string()
+        }),
+        right: ("memoized (part b)", |input| {
+            let record = &day_12::parse(input).unwrap()[0];
+            day_12::part_b::nr_arrangements_for_folded_record(record).to_string()
+        }),
+    },
+    Comparison {
+        name: "day 18 lagoon volume",
+        generate: dig_plan,
+        left: ("flood fill (part a)", |input| {
+            day_18::part_a::solve(&day_18::parse(input).unwrap()).to_string()
+        }),
+        right: ("shoelace (part b)", |input| {
+            day_18::part_b::solve(&day_18::parse(input).unwrap()).to_string()
+        }),
+    },
+];
+
+// An input on which the two algorithms of a comparison give different answers.
+#[derive(Debug, PartialEq)]
+pub struct Disagreement {
+    pub input: String,
+    pub left: String,
+    pub right: String,
+}
+
+impl Comparison {
+    // Try cases random inputs of every size from 1 to max_size, and return the smallest input on
+    // which the algorithms disagree, if any. Among inputs of the same size, the shortest is the
+    // smallest.
+    pub fn smallest_disagreement(
+        &self,
+        cases: usize,
+        max_size: usize,
+        seed: u64,
+    ) -> Option<Disagreement> {
+        let mut rng = Rng::new(seed);
+        for size in 1..=max_size {
+            let smallest = (0..cases)
+                .map(|_| (self.generate)(&mut rng, size))
+                .filter_map(|input| self.disagreement(input))
+                .min_by_key(|disagreement| disagreement.input.len());
+            if smallest.is_some() {
+                return smallest;
+            }
+        }
+        None
+    }
+
+    // A panic in either algorithm counts as an answer, which is never the same as a real one.
+    fn disagreement(&self, input: String) -> Option<Disagreement> {
+        let answer = |algorithm: fn(&str) -> String| {
+            panic::catch_unwind(AssertUnwindSafe(|| algorithm(&input)))
+                .unwrap_or_else(|_| "panicked".to_string())
+        };
+        let left = answer(self.left.1);
+        let right = answer(self.right.1);
+        (left != right).then_some(Disagreement { input, left, right })
+    }
+
+    pub fn describe(&self, disagreement: &Disagreement) -> String {
+        format!(
+            "{}: {} gives {}, {} gives {} for this input:\n{}",
+            self.name,
+            self.left.0,
+            disagreement.left,
+            self.right.0,
+            disagreement.right,
+            disagreement.input
+        )
+    }
+}
+
+// A race that can be won, as the puzzle promises: the record distance is at most the distance of
+// charging for half the time.
+fn race(rng: &mut Rng, size: usize) -> String {
+    let time = rng.range(1..=size);
+    let distance = rng.range(0..=time * time / 4);
+    format!("Time: {time}\nDistance: {distance}\n")
+}
+
+fn spring_record(rng: &mut Rng, size: usize) -> String {
+    let symbols: String = (0..size).map(|_| *rng.choose(&['.', '#', '?'])).collect();
+    let counts: Vec<String> = (0..rng.range(1..=size.div_ceil(2)))
+        .map(|_| rng.range(1..=3).to_string())
+        .collect();
+    format!("{symbols} {}\n", counts.join(","))
+}
+
+// A lagoon of size columns next to each other, each from some bottom to some top, where the
+// neighbouring columns overlap so that the trench around them does not touch itself. The plan has
+// the same steps in the direction and length of part a and in the color of part b.
+fn dig_plan(rng: &mut Rng, size: usize) -> String {
+    let mut columns: Vec<(isize, isize, isize)> = Vec::new();
+    let mut x = 0;
+    for _column in 0..size {
+        let width = rng.range(1..=3) as isize;
+        let (bottom, top) = loop {
+            let bottom = rng.range(0..=size) as isize;
+            let top = bottom + rng.range(1..=size) as isize;
+            let overlaps = columns.last().is_none_or(|&(_, last_bottom, last_top)| {
+                bottom.max(last_bottom) < top.min(last_top)
+            });
+            if overlaps {
+                break (bottom, top);
+            }
+        };
+        columns.push((x, bottom, top));
+        x += width;
+    }
+    // The corners around the columns clockwise, from the bottom left: over the tops from left to
+    // right, and back under the bottoms from right to left.
+    let right_of = |index: usize| columns.get(index + 1).map_or(x, |column| column.0);
+    let mut corners = vec![(0, columns[0].1), (0, columns[0].2)];
+    for (index, &(_, bottom, top)) in columns.iter().enumerate() {
+        corners.push((right_of(index), top));
+        let next_top = columns.get(index + 1).map_or(bottom, |column| column.2);
+        corners.push((right_of(index), next_top));
+    }
+    for (index, &(left, bottom, _)) in columns.iter().enumerate().rev() {
+        corners.push((left, bottom));
+        if index > 0 {
+            corners.push((left, columns[index - 1].1));
+        }
+    }
+    let mut steps: Vec<(char, isize)> = Vec::new();
+    for pair in corners.windows(2) {
+        let ((x1, y1), (x2, y2)) = (pair[0], pair[1]);
+        let (direction, length) = match (x2 - x1, y2 - y1) {
+            (0, dy) if dy > 0 => ('U', dy),
+            (0, dy) => ('D', -dy),
+            (dx, _) if dx > 0 => ('R', dx),
+            (dx, _) => ('L', -dx),
+        };
+        if length == 0 {
+            continue;
+        }
+        match steps.last_mut() {
+            Some(step) if step.0 == direction => step.1 += length,
+            _ => steps.push((direction, length)),
+        }
+    }
+    if steps.len() > 1 && steps[0].0 == steps[steps.len() - 1].0 {
+        let (_, length) = steps.pop().unwrap();
+        steps[0].1 += length;
+    }
+    let mut input = String::new();
+    for (direction, length) in steps {
+        let digit = match direction {
+            'R' => 0,
+            'D' => 1,
+            'L' => 2,
+            _ => 3,
+        };
+        input += &format!("{direction} {length} (#{length:05x}{digit})\n");
+    }
+    input
+}
+
+#[test]
+fn test_comparisons_agree() {
+    for comparison in COMPARISONS {
+        if let Some(disagreement) = comparison.smallest_disagreement(50, 8, 0) {
+            panic!("{}", comparison.describe(&disagreement));
+        }
+    }
+}
+
+#[test]
+fn test_smallest_disagreement() {
+    // Counting the characters of the input differs from counting them up to 5 from size 5 on
+    let comparison = Comparison {
+        name: "test",
+        generate: |rng, size| "x".repeat(rng.range(size..=size + 1)),
+        left: ("all", |input| input.len().to_string()),
+        right: ("up to 5", |input| input.len().min(5).to_string()),
+    };
+    let disagreement = comparison.smallest_disagreement(10, 10, 0).unwrap();
+    assert_eq!(disagreement.input, "xxxxxx");
+    assert_eq!(disagreement.left, "6");
+    assert_eq!(comparison.smallest_disagreement(10, 4, 0), None);
+}
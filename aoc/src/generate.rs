@@ -0,0 +1,189 @@
+// Generators of random puzzle inputs of a chosen size, for stress testing how the solvers scale and
+// fuzzing their parsers. The inputs are structurally valid, but unlike real puzzle inputs they are
+// not tuned to have a nice answer. The same day, size and seed always give the same input.
+
+use crate::random::Rng;
+use std::collections::HashSet;
+
+// The days that have a generator.
+pub const DAYS: &[u32] = &[11, 12, 16, 19, 20];
+
+// What the size means depends on the day:
+// - day 11: the width and height of the galaxy map
+// - day 12: the number of spring records
+// - day 16: the width and height of the grid of mirrors and splitters
+// - day 19: the number of workflows, and the number of parts
+// - day 20: the number of modules, besides the broadcaster
+pub fn generate(day: u32, size: usize, seed: u64) -> Option<String> {
+    let mut rng = Rng::new(seed);
+    let input = match day {
+        11 => galaxy_map(size, &mut rng),
+        12 => spring_records(size, &mut rng),
+        16 => contraption(size, &mut rng),
+        19 => workflows_and_parts(size, &mut rng),
+        20 => module_network(size, &mut rng),
+        _ => return None,
+    };
+    Some(input)
+}
+
+// Some rows and columns are left without galaxies, so that the universe expands.
+fn galaxy_map(size: usize, rng: &mut Rng) -> String {
+    let empty_columns: Vec<bool> = (0..size).map(|_| rng.one_in(8)).collect();
+    let mut input = String::new();
+    for _y in 0..size {
+        let empty_row = rng.one_in(8);
+        for &empty_column in &empty_columns {
+            let galaxy = !empty_row && !empty_column && rng.one_in(20);
+            input.push(if galaxy { '#' } else { '.' });
+        }
+        input.push('\n');
+    }
+    input
+}
+
+// Each record starts out as a row of springs with known groups of damaged springs, so it has at
+// least one arrangement, and then some of its springs are hidden as unknown.
+fn spring_records(size: usize, rng: &mut Rng) -> String {
+    let mut input = String::new();
+    for _record in 0..size {
+        let counts: Vec<usize> = (0..rng.range(1..=4)).map(|_| rng.range(1..=5)).collect();
+        let mut springs = ".".repeat(rng.range(0..=2));
+        for count in &counts {
+            springs += &"#".repeat(*count);
+            springs += &".".repeat(rng.range(1..=2));
+        }
+        let springs: String = springs
+            .chars()
+            .map(|c| if rng.one_in(3) { '?' } else { c })
+            .collect();
+        let counts: Vec<String> = counts.iter().map(|count| count.to_string()).collect();
+        input += &format!("{springs} {}\n", counts.join(","));
+    }
+    input
+}
+
+fn contraption(size: usize, rng: &mut Rng) -> String {
+    let mut input = String::new();
+    for _y in 0..size {
+        for _x in 0..size {
+            let tile = if rng.one_in(8) {
+                *rng.choose(&['/', '\\', '|', '-'])
+            } else {
+                '.'
+            };
+            input.push(tile);
+        }
+        input.push('\n');
+    }
+    input
+}
+
+// The workflows only send parts to workflows after them, so that no part goes round in circles.
+fn workflows_and_parts(size: usize, rng: &mut Rng) -> String {
+    let mut names = vec!["in".to_string()];
+    names.extend(unique_names(size.saturating_sub(1), &["in"], rng));
+    let mut input = String::new();
+    for (index, name) in names.iter().enumerate() {
+        let later = &names[index + 1..];
+        let target = |rng: &mut Rng| {
+            if later.is_empty() || rng.one_in(3) {
+                rng.choose(&["A", "R"]).to_string()
+            } else {
+                rng.choose(later).clone()
+            }
+        };
+        let rules: Vec<String> = (0..rng.range(1..=4))
+            .map(|_| {
+                let category = rng.choose(&['x', 'm', 'a', 's']);
+                let comparison = rng.choose(&['<', '>']);
+                let value = rng.range(1..=4000);
+                format!("{category}{comparison}{value}:{}", target(rng))
+            })
+            .collect();
+        input += &format!("{name}{{{},{}}}\n", rules.join(","), target(rng));
+    }
+    input.push('\n');
+    for _part in 0..size {
+        let [x, m, a, s] = [(); 4].map(|_| rng.range(1..=4000));
+        input += &format!("{{x={x},m={m},a={a},s={s}}}\n");
+    }
+    input
+}
+
+// The modules only send pulses to modules after them, so that pushing the button always ends.
+fn module_network(size: usize, rng: &mut Rng) -> String {
+    let names = unique_names(size, &["broadcaster", "output"], rng);
+    let destinations = |after: usize, rng: &mut Rng| {
+        let later = &names[after..];
+        let destinations: Vec<&str> = if later.is_empty() {
+            vec!["output"]
+        } else {
+            (0..rng.range(1..=3).min(later.len()))
+                .map(|_| rng.choose(later).as_str())
+                .collect()
+        };
+        destinations.join(", ")
+    };
+    let mut input = format!("broadcaster -> {}\n", destinations(0, rng));
+    for (index, name) in names.iter().enumerate() {
+        let module_type = rng.choose(&['%', '&']);
+        input += &format!("{module_type}{name} -> {}\n", destinations(index + 1, rng));
+    }
+    input
+}
+
+// Random names of two or three lowercase letters, like the puzzle uses, other than the reserved ones.
+// Once half of the names up to the longest length are taken, names can be a letter longer, so that
+// there are always enough of them.
+fn unique_names(count: usize, reserved: &[&str], rng: &mut Rng) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+    let mut seen: HashSet<String> = reserved.iter().map(|name| name.to_string()).collect();
+    let mut longest = 3;
+    while names.len() < count {
+        let available: usize = (2..=longest).map(|length| 26usize.pow(length)).sum();
+        if 2 * seen.len() >= available {
+            longest += 1;
+        }
+        let name: String = (0..rng.range(2..=longest as usize))
+            .map(|_| (b'a' + rng.range(0..=25) as u8) as char)
+            .collect();
+        if seen.insert(name.clone()) {
+            names.push(name);
+        }
+    }
+    names
+}
+
+#[test]
+fn test_generate() {
+    assert_eq!(generate(12, 20, 1), generate(12, 20, 1));
+    assert_ne!(generate(12, 20, 1), generate(12, 20, 2));
+    assert_eq!(generate(11, 5, 1).unwrap().lines().count(), 5);
+    assert_eq!(generate(1, 5, 1), None);
+}
+
+// There are more names than those of two or three letters.
+#[test]
+fn test_unique_names() {
+    let names = unique_names(20000, &["in"], &mut Rng::new(1));
+    let distinct: HashSet<&String> = names.iter().collect();
+    assert_eq!(distinct.len(), 20000);
+    assert!(!distinct.contains(&"in".to_string()));
+}
+
+// Every generated input must be solvable by both parts of its day.
+#[test]
+fn test_generated_inputs_are_valid() {
+    for &day in DAYS {
+        for seed in 0..5 {
+            let input = generate(day, 10, seed).unwrap();
+            for solver in crate::registry::SOLVERS.iter().filter(|s| s.day == day) {
+                let parsed = (solver.parse)(&input).unwrap_or_else(|error| {
+                    panic!("Day {day} seed {seed}: {error}\n{input}");
+                });
+                (solver.solve)(parsed.as_ref());
+            }
+        }
+    }
+}
@@ -0,0 +1,386 @@
+// Run history: every part that the runner solves is appended to a CSV file with the input it solved
+// (by name and by a hash of its contents), the git commit of the code, the answer and how long it
+// took. The history of a day shows how its answers and timings changed over time, and points out
+// where the answer for the same input changed.
+
+use aoc_common::{Answer, InputSource, Solver};
+use std::fs::{self, OpenOptions};
+use std::io::{IsTerminal, Write, stdout};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::OnceLock;
+use std::time::{Duration, SystemTime};
+
+// Where to keep the history instead of aoc_history.csv in the workspace, or nothing to keep none.
+const HISTORY_ENV: &str = "AOC_HISTORY";
+
+const HEADER: &str = "timestamp,day,part,input,input_hash,commit,answer,elapsed_ns";
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Entry {
+    // Seconds since the Unix epoch
+    pub timestamp: u64,
+    pub day: u32,
+    pub part: char,
+    // The file name for inputs in the directory of the day crate, the path for other files, or -
+    // for stdin
+    pub input: String,
+    pub input_hash: String,
+    pub commit: String,
+    // None if the part could not be solved
+    pub answer: Option<String>,
+    pub elapsed: Duration,
+}
+
+impl Entry {
+    pub fn new(
+        solver: &Solver,
+        source: &InputSource,
+        answer: Option<&Answer>,
+        elapsed: Duration,
+    ) -> Entry {
+        let dir = Path::new(solver.dir);
+        let input = match source.path(dir) {
+            Some(path) if path.parent() == Some(dir) => {
+                path.file_name().unwrap().to_string_lossy().to_string()
+            }
+            Some(path) => path.display().to_string(),
+            None => "-".to_string(),
+        };
+        // The input is read again, which is cheap next to solving it, except for stdin, which the
+        // solver has read already
+        let input_hash = match source {
+            InputSource::Stdin => String::new(),
+            source => solver
+                .read_input(source)
+                .map_or(String::new(), |input| hash(&input)),
+        };
+        Entry {
+            timestamp: SystemTime::now()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .map_or(0, |since| since.as_secs()),
+            day: solver.day,
+            part: solver.part,
+            input,
+            input_hash,
+            commit: git_commit().to_string(),
+            answer: answer.map(Answer::to_string),
+            elapsed,
+        }
+    }
+
+    fn to_csv(&self) -> String {
+        [
+            self.timestamp.to_string(),
+            self.day.to_string(),
+            self.part.to_string(),
+            self.input.clone(),
+            self.input_hash.clone(),
+            self.commit.clone(),
+            self.answer.clone().unwrap_or_default(),
+            self.elapsed.as_nanos().to_string(),
+        ]
+        .iter()
+        .map(|field| csv_field(field))
+        .collect::<Vec<String>>()
+        .join(",")
+    }
+
+    fn from_csv(line: &str) -> Option<Entry> {
+        let fields = split_csv(line)?;
+        let [
+            timestamp,
+            day,
+            part,
+            input,
+            input_hash,
+            commit,
+            answer,
+            elapsed_ns,
+        ] = &fields[..]
+        else {
+            return None;
+        };
+        Some(Entry {
+            timestamp: timestamp.parse().ok()?,
+            day: day.parse().ok()?,
+            part: part.parse().ok()?,
+            input: input.clone(),
+            input_hash: input_hash.clone(),
+            commit: commit.clone(),
+            answer: (!answer.is_empty()).then(|| answer.clone()),
+            elapsed: Duration::from_nanos(elapsed_ns.parse().ok()?),
+        })
+    }
+}
+
+// The FNV-1a hash of the input, which is enough to tell inputs apart.
+pub fn hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf29ce484222325_u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    });
+    format!("{hash:016x}")
+}
+
+// The commit that is checked out, with -dirty if there are changes that are not committed, or
+// nothing if git can't tell.
+fn git_commit() -> &'static str {
+    static COMMIT: OnceLock<String> = OnceLock::new();
+    COMMIT.get_or_init(|| {
+        let git = |args: &[&str]| {
+            Command::new("git")
+                .args(args)
+                .current_dir(env!("CARGO_MANIFEST_DIR"))
+                .output()
+                .ok()
+                .filter(|output| output.status.success())
+                .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        };
+        let Some(commit) = git(&["rev-parse", "--short", "HEAD"]) else {
+            return String::new();
+        };
+        match git(&["status", "--porcelain", "--untracked-files=no"]) {
+            Some(changes) if !changes.is_empty() => format!("{commit}-dirty"),
+            _ => commit,
+        }
+    })
+}
+
+pub fn path() -> Option<PathBuf> {
+    match std::env::var_os(HISTORY_ENV) {
+        Some(path) if path.is_empty() => None,
+        Some(path) => Some(PathBuf::from(path)),
+        None => Some(Path::new(env!("CARGO_MANIFEST_DIR")).join("../aoc_history.csv")),
+    }
+}
+
+pub fn append(path: &Path, entries: &[Entry]) -> Result<(), String> {
+    let error = |err| format!("Cannot write history {}: {err}", path.display());
+    let new = !path.exists();
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(error)?;
+    let mut text = String::new();
+    if new {
+        text += HEADER;
+        text.push('\n');
+    }
+    for entry in entries {
+        text += &entry.to_csv();
+        text.push('\n');
+    }
+    file.write_all(text.as_bytes()).map_err(error)
+}
+
+// Record the entries in the history, if one is kept. The runs are done by now, so a history that
+// can't be written is only a warning.
+pub fn record(entries: &[Entry]) {
+    if let Some(path) = path()
+        && let Err(message) = append(&path, entries)
+    {
+        eprintln!("warning: {message}");
+    }
+}
+
+pub fn read(path: &Path) -> Result<Vec<Entry>, String> {
+    let text = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(format!("Cannot read history {}: {err}", path.display())),
+    };
+    text.lines()
+        .skip(1)
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            Entry::from_csv(line)
+                .ok_or_else(|| format!("Invalid line in history {}: '{line}'", path.display()))
+        })
+        .collect()
+}
+
+// The entries of the day (and part) with each input in a table of its own, oldest first. Rows where
+// the answer differs from the row before are flagged, and in a terminal shown in red.
+pub fn report(entries: &[Entry], day: u32, part: Option<char>) -> String {
+    let mut entries: Vec<&Entry> = entries
+        .iter()
+        .filter(|entry| entry.day == day && part.is_none_or(|part| entry.part == part))
+        .collect();
+    if entries.is_empty() {
+        return format!("No runs of day {day} in the history\n");
+    }
+    entries.sort_by(|a, b| {
+        (a.part, &a.input, &a.input_hash, a.timestamp).cmp(&(
+            b.part,
+            &b.input,
+            &b.input_hash,
+            b.timestamp,
+        ))
+    });
+    let width = entries
+        .iter()
+        .map(|entry| entry.answer.as_ref().map_or(1, String::len))
+        .max()
+        .unwrap();
+    let color = stdout().is_terminal();
+    let mut report = String::new();
+    for group in entries
+        .chunk_by(|a, b| (a.part, &a.input, &a.input_hash) == (b.part, &b.input, &b.input_hash))
+    {
+        let first = group[0];
+        report += &format!(
+            "Day {} part {}, {} ({}):\n",
+            first.day, first.part, first.input, first.input_hash
+        );
+        let mut previous: Option<&Entry> = None;
+        for entry in group {
+            let answer = entry.answer.as_deref().unwrap_or("-");
+            let mut line = format!(
+                "  {}  {:<13}  {answer:>width$}  {:>10.1?}",
+                format_timestamp(entry.timestamp),
+                entry.commit,
+                entry.elapsed
+            );
+            if let Some(previous) = previous
+                && previous.answer != entry.answer
+            {
+                let was = previous.answer.as_deref().unwrap_or("-");
+                line += &format!("  answer changed, was {was}");
+                if color {
+                    line = format!("\x1b[31m{line}\x1b[0m");
+                }
+            }
+            report += &line;
+            report.push('\n');
+            previous = Some(entry);
+        }
+    }
+    report
+}
+
+// The time in UTC, as 2023-12-01 05:00:00.
+fn format_timestamp(timestamp: u64) -> String {
+    let (days, seconds) = (timestamp / 86400, timestamp % 86400);
+    // The civil date of a day number, see http://howardhinnant.github.io/date_algorithms.html
+    let z = days as i64 + 719468;
+    let era = z.div_euclid(146097);
+    let day_of_era = z.rem_euclid(146097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    format!(
+        "{year:04}-{month:02}-{day:02} {:02}:{:02}:{:02}",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+// Quote a field that contains a comma, quote or newline, doubling its quotes.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn split_csv(line: &str) -> Option<Vec<String>> {
+    let mut fields = Vec::new();
+    let mut chars = line.chars().peekable();
+    loop {
+        let mut field = String::new();
+        if chars.peek() == Some(&'"') {
+            chars.next();
+            loop {
+                match chars.next()? {
+                    '"' if chars.peek() == Some(&'"') => {
+                        chars.next();
+                        field.push('"');
+                    }
+                    '"' => break,
+                    c => field.push(c),
+                }
+            }
+        }
+        while let Some(&c) = chars.peek()
+            && c != ','
+        {
+            field.push(c);
+            chars.next();
+        }
+        fields.push(field);
+        if chars.next().is_none() {
+            return Some(fields);
+        }
+    }
+}
+
+#[test]
+fn test_csv() {
+    let entry = Entry {
+        timestamp: 1701406800,
+        day: 5,
+        part: 'b',
+        input: "inputs/a, \"b\"".to_string(),
+        input_hash: hash("seeds: 1 2\n"),
+        commit: "7fb6c75-dirty".to_string(),
+        answer: Some("26714516".to_string()),
+        elapsed: Duration::from_nanos(1234),
+    };
+    let line = entry.to_csv();
+    assert_eq!(
+        line,
+        "1701406800,5,b,\"inputs/a, \"\"b\"\"\",39d847c3c72c191a,7fb6c75-dirty,26714516,1234"
+    );
+    assert_eq!(Entry::from_csv(&line), Some(entry.clone()));
+    let failed = Entry {
+        answer: None,
+        ..entry
+    };
+    assert_eq!(Entry::from_csv(&failed.to_csv()), Some(failed));
+    assert_eq!(Entry::from_csv("1,2,3"), None);
+}
+
+#[test]
+fn test_report() {
+    let entry = |timestamp, input_hash: &str, answer: &str| Entry {
+        timestamp,
+        day: 5,
+        part: 'a',
+        input: "puzzle_input".to_string(),
+        input_hash: input_hash.to_string(),
+        commit: "abc1234".to_string(),
+        answer: Some(answer.to_string()),
+        elapsed: Duration::from_millis(2),
+    };
+    let entries = [
+        entry(1701406800, "11", "35"),
+        entry(1701493200, "22", "7"),
+        entry(1701410400, "11", "35"),
+        entry(1701414000, "11", "36"),
+    ];
+    assert_eq!(
+        report(&entries, 5, None),
+        "Day 5 part a, puzzle_input (11):
+  2023-12-01 05:00:00  abc1234        35       2.0ms
+  2023-12-01 06:00:00  abc1234        35       2.0ms
+  2023-12-01 07:00:00  abc1234        36       2.0ms  answer changed, was 35
+Day 5 part a, puzzle_input (22):
+  2023-12-02 05:00:00  abc1234         7       2.0ms
+"
+    );
+    assert_eq!(
+        report(&entries, 6, None),
+        "No runs of day 6 in the history\n"
+    );
+}
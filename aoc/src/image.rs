@@ -0,0 +1,161 @@
+// Writing pictures of puzzles as image files, where every cell of the picture becomes a square of
+// cell_size by cell_size pixels. The format follows from the extension of the file: .ppm, .png or
+// .svg. The encoders are simple rather than small: the PNG is not compressed.
+
+use aoc_common::{Grid, Rgb};
+use std::fs;
+use std::path::Path;
+
+pub fn write_image(colors: &Grid<Rgb>, cell_size: usize, path: &Path) -> Result<(), String> {
+    let extension = path.extension().and_then(|extension| extension.to_str());
+    let bytes = match extension.map(str::to_ascii_lowercase).as_deref() {
+        Some("ppm") => ppm(colors, cell_size),
+        Some("png") => png(colors, cell_size),
+        Some("svg") => svg(colors, cell_size).into_bytes(),
+        _ => {
+            return Err(format!(
+                "Unknown image format of {}, expected .ppm, .png or .svg",
+                path.display()
+            ));
+        }
+    };
+    fs::write(path, bytes).map_err(|err| format!("Cannot write {}: {err}", path.display()))
+}
+
+// The rows of pixels, each with 3 bytes per pixel.
+fn pixel_rows(colors: &Grid<Rgb>, cell_size: usize) -> Vec<Vec<u8>> {
+    let mut rows = Vec::new();
+    for row in colors.rows() {
+        let pixels: Vec<u8> = row
+            .iter()
+            .flat_map(|&Rgb(r, g, b)| [r, g, b].repeat(cell_size))
+            .collect();
+        for _ in 0..cell_size {
+            rows.push(pixels.clone());
+        }
+    }
+    rows
+}
+
+fn ppm(colors: &Grid<Rgb>, cell_size: usize) -> Vec<u8> {
+    let (width, height) = (colors.width() * cell_size, colors.height() * cell_size);
+    let mut bytes = format!("P6\n{width} {height}\n255\n").into_bytes();
+    bytes.extend(pixel_rows(colors, cell_size).concat());
+    bytes
+}
+
+fn png(colors: &Grid<Rgb>, cell_size: usize) -> Vec<u8> {
+    let (width, height) = (colors.width() * cell_size, colors.height() * cell_size);
+    let mut header = Vec::new();
+    header.extend((width as u32).to_be_bytes());
+    header.extend((height as u32).to_be_bytes());
+    // 8 bits per channel, RGB, default compression, filtering and no interlacing
+    header.extend([8, 2, 0, 0, 0]);
+    // Every row starts with filter type 0, no filtering
+    let mut data = Vec::new();
+    for row in pixel_rows(colors, cell_size) {
+        data.push(0);
+        data.extend(row);
+    }
+    let mut bytes = vec![0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+    png_chunk(&mut bytes, b"IHDR", &header);
+    png_chunk(&mut bytes, b"IDAT", &zlib_stored(&data));
+    png_chunk(&mut bytes, b"IEND", &[]);
+    bytes
+}
+
+fn png_chunk(bytes: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    bytes.extend((data.len() as u32).to_be_bytes());
+    let start = bytes.len();
+    bytes.extend(kind);
+    bytes.extend(data);
+    let crc = crc32(&bytes[start..]);
+    bytes.extend(crc.to_be_bytes());
+}
+
+// A zlib stream with the data in stored (uncompressed) deflate blocks.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut bytes = vec![0x78, 0x01];
+    let blocks: Vec<&[u8]> = data.chunks(u16::MAX as usize).collect();
+    if blocks.is_empty() {
+        bytes.extend([1, 0, 0, 0xff, 0xff]);
+    }
+    for (index, block) in blocks.iter().enumerate() {
+        let last = index == blocks.len() - 1;
+        let length = block.len() as u16;
+        bytes.push(last as u8);
+        bytes.extend(length.to_le_bytes());
+        bytes.extend((!length).to_le_bytes());
+        bytes.extend(*block);
+    }
+    bytes.extend(adler32(data).to_be_bytes());
+    bytes
+}
+
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in bytes {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in bytes {
+        a = (a + byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    (b << 16) | a
+}
+
+// Each run of cells with the same color in a row is one rectangle.
+fn svg(colors: &Grid<Rgb>, cell_size: usize) -> String {
+    let (width, height) = (colors.width() * cell_size, colors.height() * cell_size);
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" shape-rendering=\"crispEdges\">\n"
+    );
+    for (y, row) in colors.rows().enumerate() {
+        let mut x = 0;
+        for run in row.chunk_by(|a, b| a == b) {
+            let Rgb(r, g, b) = run[0];
+            svg += &format!(
+                "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{cell_size}\" fill=\"#{r:02x}{g:02x}{b:02x}\"/>\n",
+                x * cell_size,
+                y * cell_size,
+                run.len() * cell_size
+            );
+            x += run.len();
+        }
+    }
+    svg += "</svg>\n";
+    svg
+}
+
+#[test]
+fn test_checksums() {
+    assert_eq!(crc32(b"IEND"), 0xae42_6082);
+    assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+}
+
+#[test]
+fn test_image_formats() {
+    let (black, white) = (Rgb(0, 0, 0), Rgb(255, 255, 255));
+    let colors = Grid::from_cells(3, 1, vec![black, black, white]);
+    let ppm = ppm(&colors, 2);
+    assert!(ppm.starts_with(b"P6\n6 2\n255\n"));
+    assert_eq!(ppm.len(), 11 + 6 * 2 * 3);
+    let svg = svg(&colors, 2);
+    assert_eq!(svg.matches("<rect").count(), 2);
+    assert!(svg.contains("x=\"0\" y=\"0\" width=\"4\" height=\"2\" fill=\"#000000\""));
+    let png = png(&colors, 2);
+    assert!(png.starts_with(&[0x89, b'P', b'N', b'G']));
+    assert!(png.ends_with(&[0xae, 0x42, 0x60, 0x82]));
+}
@@ -0,0 +1,116 @@
+// JSON output: one object per line for each day and part that was run, so that scripts don't have to
+// scrape the text output.
+
+use crate::alloc::{Profile, Usage};
+use crate::bench::{PhaseTiming, Timing};
+use aoc_common::{Answer, Error, InputSource, Solver};
+use serde_json::{Number, Value, json};
+use std::path::Path;
+use std::time::Duration;
+
+pub fn run_object(
+    solver: &Solver,
+    source: &InputSource,
+    result: &Result<Answer, Error>,
+    elapsed: Duration,
+) -> Value {
+    let input = source
+        .path(Path::new(solver.dir))
+        .map(|path| path.display().to_string());
+    let mut object = json!({
+        "day": solver.day,
+        "part": solver.part.to_string(),
+        "description": solver.description,
+        "input": input,
+        "elapsed_ns": elapsed.as_nanos() as u64,
+    });
+    match result {
+        Ok(answer) => object["answer"] = answer_value(answer),
+        Err(error) => {
+            object["answer"] = Value::Null;
+            object["error"] = Value::String(error_message(error));
+        }
+    }
+    object
+}
+
+// The run object of a benchmark, where elapsed is the median time to parse and solve.
+pub fn bench_object(
+    solver: &Solver,
+    source: &InputSource,
+    answer: Answer,
+    timing: &Timing,
+    repeats: u32,
+    regressions: &[String],
+) -> Value {
+    let elapsed = timing.parse.median + timing.solve.median;
+    let mut object = run_object(solver, source, &Ok(answer), elapsed);
+    object["bench"] = json!({
+        "repeats": repeats,
+        "parse": phase_value(&timing.parse),
+        "solve": phase_value(&timing.solve),
+        "regressions": regressions,
+    });
+    object
+}
+
+// The metrics of a run, by name, in the order in which the solver first updated them.
+pub fn stats_value(stats: &[(&str, u64)]) -> Value {
+    Value::Object(
+        stats
+            .iter()
+            .map(|&(name, value)| (name.to_string(), json!(value)))
+            .collect(),
+    )
+}
+
+// The allocations of the parse and solve phases of a run.
+pub fn alloc_value(profile: &Profile) -> Value {
+    let usage_value = |usage: &Usage| {
+        json!({
+            "allocations": usage.allocations,
+            "bytes": usage.bytes,
+            "peak_bytes": usage.peak,
+        })
+    };
+    json!({
+        "parse": usage_value(&profile.parse),
+        "solve": usage_value(&profile.solve),
+    })
+}
+
+// Integer answers are JSON numbers, unless they don't fit in 64 bits.
+fn answer_value(answer: &Answer) -> Value {
+    match answer {
+        Answer::Int(n) => Number::from_i128(*n)
+            .map(Value::Number)
+            .unwrap_or_else(|| Value::String(n.to_string())),
+        Answer::Text(text) => Value::String(text.clone()),
+    }
+}
+
+fn error_message(error: &Error) -> String {
+    match error {
+        Error::Input(message) => message.clone(),
+        Error::Parse(error) => error.to_string(),
+        Error::Overflow(message) => format!("arithmetic overflow: {message}"),
+    }
+}
+
+fn phase_value(phase: &PhaseTiming) -> Value {
+    json!({
+        "min_ns": phase.min.as_nanos() as u64,
+        "median_ns": phase.median.as_nanos() as u64,
+        "max_ns": phase.max.as_nanos() as u64,
+    })
+}
+
+#[test]
+fn test_answer_value() {
+    assert_eq!(answer_value(&Answer::Int(-12)), json!(-12));
+    assert_eq!(
+        answer_value(&Answer::Int(1 << 70)),
+        json!("1180591620717411303424")
+    );
+    assert_eq!(answer_value(&Answer::Text("ABC".to_string())), json!("ABC"));
+}
@@ -0,0 +1,750 @@
+// The aoc runner: a single binary that dispatches to the solver of every day and part.
+
+mod alloc;
+mod animate;
+mod batch;
+mod bench;
+mod check;
+mod differential;
+mod generate;
+mod history;
+mod image;
+mod json;
+mod random;
+mod registry;
+mod scaffold;
+mod watch;
+
+use aoc_common::{InputSource, Rgb, Solver, metrics, trace};
+use bench::{Baseline, time_solver};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use registry::SOLVERS;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+// Counts allocations only while a phase is measured, see alloc.rs
+#[global_allocator]
+static ALLOCATOR: alloc::Counting = alloc::Counting;
+
+#[derive(Parser)]
+#[command(about = "Advent of code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Trace the events of these targets, e.g. day20::pulse,day16=debug (default: $AOC_TRACE)
+    #[arg(long, global = true)]
+    trace: Option<String>,
+    /// Write the trace to this file instead of stderr (default: $AOC_TRACE_FILE)
+    #[arg(long, global = true)]
+    trace_file: Option<PathBuf>,
+    /// Report a byte order mark, Windows line endings, trailing whitespace or a missing or extra
+    /// newline at the end of the input as an error, instead of normalizing the input
+    #[arg(long, global = true)]
+    strict: bool,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one day (both parts, unless a part is given), or all days
+    Run {
+        #[arg(long, required_unless_present = "all", conflicts_with = "all")]
+        day: Option<u32>,
+        #[arg(long, requires = "day")]
+        part: Option<char>,
+        #[arg(long, conflicts_with = "input")]
+        all: bool,
+        #[command(flatten)]
+        input: InputArgs,
+        #[command(flatten)]
+        bench: BenchArgs,
+        #[command(flatten)]
+        animate: AnimateArgs,
+        /// How to print the answers
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+        /// Print the metrics of each solver, e.g. how many states it explored, after its answer
+        #[arg(long, conflicts_with_all = ["bench", "animate"])]
+        stats: bool,
+        /// Print the number of allocations, the bytes allocated and the peak heap of the parse and
+        /// solve phases of each solver after its answer
+        #[arg(long, conflicts_with_all = ["bench", "animate"])]
+        alloc: bool,
+    },
+    /// Solve the puzzle input of every day and part in parallel, and check the answers against the
+    /// answers manifests
+    Check {
+        /// Only check this day
+        #[arg(long)]
+        day: Option<u32>,
+        #[command(flatten)]
+        pool: PoolArgs,
+    },
+    /// Rebuild and solve a day (both parts, unless a part is given) again whenever its sources or
+    /// inputs change
+    Watch {
+        #[arg(long)]
+        day: u32,
+        #[arg(long)]
+        part: Option<char>,
+        #[command(flatten)]
+        input: InputArgs,
+        /// How often to look for changes, in milliseconds
+        #[arg(long, default_value_t = 500)]
+        interval: u64,
+    },
+    /// Solve a day (both parts, unless a part is given) for every input file in a directory
+    Batch {
+        #[arg(long)]
+        day: u32,
+        #[arg(long)]
+        part: Option<char>,
+        /// The directory with the input files
+        dir: PathBuf,
+        #[command(flatten)]
+        pool: PoolArgs,
+    },
+    /// Compare the days that have two algorithms for the same quantity on random small inputs, and
+    /// print the smallest input on which they disagree
+    Differential {
+        /// How many random inputs to try of each size
+        #[arg(long, default_value_t = 200)]
+        cases: usize,
+        /// The largest size of input to try
+        #[arg(long, default_value_t = 12)]
+        max_size: usize,
+        /// The same seed always tries the same inputs
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+    },
+    /// Show how the answers and timings of a day changed over the runs in the history, and where
+    /// an answer changed
+    History {
+        #[arg(long)]
+        day: u32,
+        #[arg(long)]
+        part: Option<char>,
+    },
+    /// List all registered days and parts
+    List,
+    /// Create the crate of a new day from a template, and register it with the runner
+    New {
+        day: u32,
+        /// What the answer is, e.g. "Sum of calibration values"
+        #[arg(long, default_value = "Answer")]
+        description: String,
+    },
+    /// Print a random input for a day, for stress testing
+    Generate {
+        #[arg(long)]
+        day: u32,
+        /// How big the input is, e.g. the number of lines or the width of the grid, depending on the day
+        #[arg(long)]
+        size: usize,
+        /// The same seed always gives the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+    },
+    /// Solve a day and draw a picture of the puzzle as an image file
+    Render {
+        #[arg(long)]
+        day: u32,
+        #[arg(long)]
+        part: Option<char>,
+        #[command(flatten)]
+        input: InputArgs,
+        /// The image file to write, a .ppm, .png or .svg
+        #[arg(long, short)]
+        output: PathBuf,
+        /// The width and height of each cell in pixels
+        #[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u32).range(1..))]
+        cell_size: u32,
+        /// Colors for kinds of cell instead of the default ones, e.g. --palette inside=#ffcc00,loop=#000000
+        #[arg(long, value_name = "KIND=COLOR", value_delimiter = ',', value_parser = parse_palette_entry)]
+        palette: Vec<(String, Rgb)>,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    /// One line of text per answer, with errors on stderr
+    Text,
+    /// One JSON object per line with the answer (or error), input and elapsed time of each run
+    Json,
+}
+
+#[derive(Args)]
+struct PoolArgs {
+    /// How many solvers to run at the same time, by default one per CPU
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..))]
+    jobs: Option<u32>,
+    /// How many seconds each solver may take before it counts as timed out
+    #[arg(long, default_value_t = 60.0)]
+    timeout: f64,
+}
+
+impl PoolArgs {
+    fn jobs(&self) -> usize {
+        self.jobs.map_or_else(
+            || std::thread::available_parallelism().map_or(1, |jobs| jobs.get()),
+            |jobs| jobs as usize,
+        )
+    }
+
+    fn timeout(&self) -> Result<Duration, String> {
+        Duration::try_from_secs_f64(self.timeout)
+            .map_err(|_| format!("Invalid timeout: {}", self.timeout))
+    }
+}
+
+#[derive(Args)]
+struct InputArgs {
+    /// Input file to solve instead of the puzzle input, or - for stdin
+    #[arg(conflicts_with = "example")]
+    input: Option<PathBuf>,
+    /// Solve the example input of the day instead, or its Nth example if it has several
+    #[arg(long, value_name = "N")]
+    example: Option<Option<u32>>,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    /// Time the parse and solve phases, repeating them, instead of only printing the answers
+    #[arg(long)]
+    bench: bool,
+    /// How many times to parse and solve the input when benchmarking
+    #[arg(long, requires = "bench", default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+    repeats: u32,
+    /// Save the median timings to a baseline file, replacing earlier timings of the same parts
+    #[arg(long, requires = "bench", value_name = "FILE")]
+    save_baseline: Option<PathBuf>,
+    /// Compare the median timings against a baseline file, and fail if any of them regressed
+    #[arg(long, requires = "bench", value_name = "FILE")]
+    baseline: Option<PathBuf>,
+    /// How many percent slower than the baseline counts as a regression
+    #[arg(long, requires = "baseline", default_value_t = 10.0)]
+    threshold: f64,
+}
+
+#[derive(Args)]
+struct AnimateArgs {
+    /// Show the solution step by step in the terminal, for the days that can animate it. Keys:
+    /// space pauses, n steps while paused, + and - change the speed, q stops
+    #[arg(long, requires = "day", conflicts_with_all = ["bench", "format"])]
+    animate: bool,
+    /// How many frames to show per second
+    #[arg(long, requires = "animate", default_value_t = 10.0)]
+    fps: f64,
+    /// Stop showing frames after this many, and only solve the rest of the puzzle
+    #[arg(long, requires = "animate", value_name = "N")]
+    max_frames: Option<usize>,
+}
+
+impl InputArgs {
+    fn source(&self) -> InputSource {
+        match (&self.input, self.example) {
+            (Some(path), _) if path.as_os_str() == "-" => InputSource::Stdin,
+            (Some(path), _) => InputSource::Path(path.clone()),
+            (None, Some(number)) => InputSource::Example(number),
+            (None, None) => InputSource::Puzzle,
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    if let Err(err) = configure_tracing(&cli) {
+        eprintln!("{err}");
+        return ExitCode::FAILURE;
+    }
+    aoc_common::set_strict(cli.strict);
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            all,
+            input,
+            bench,
+            animate,
+            format,
+            stats,
+            alloc,
+        } => {
+            let solvers = if all {
+                SOLVERS.iter().collect()
+            } else {
+                select_solvers(day.unwrap(), part)
+            };
+            if solvers.is_empty() {
+                eprintln!("No solver registered for {}", describe_selection(day, part));
+                return ExitCode::FAILURE;
+            }
+            let source = input.source();
+            if bench.bench {
+                return run_bench(&solvers, &source, &bench, format);
+            }
+            if animate.animate {
+                return run_animations(&solvers, &source, &animate);
+            }
+            let mut failed = false;
+            for solver in solvers {
+                if !run_solver(solver, &source, format, stats, alloc) {
+                    failed = true;
+                }
+            }
+            if failed {
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Check { day, pool } => {
+            let solvers = match day {
+                Some(day) => select_solvers(day, None),
+                None => SOLVERS.iter().collect(),
+            };
+            if solvers.is_empty() {
+                eprintln!("No solver registered for {}", describe_selection(day, None));
+                return ExitCode::FAILURE;
+            }
+            let timeout = match pool.timeout() {
+                Ok(timeout) => timeout,
+                Err(message) => {
+                    eprintln!("{message}");
+                    return ExitCode::FAILURE;
+                }
+            };
+            let outcomes = check::check(&solvers, pool.jobs(), timeout);
+            let entries: Vec<history::Entry> = outcomes
+                .iter()
+                .map(|outcome| {
+                    history::Entry::new(
+                        outcome.solver,
+                        &InputSource::Puzzle,
+                        outcome.answer.as_ref(),
+                        outcome.elapsed,
+                    )
+                })
+                .collect();
+            history::record(&entries);
+            print!("{}", check::table(&outcomes));
+            if outcomes
+                .iter()
+                .any(|outcome| outcome.status != check::Status::Ok)
+            {
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Watch {
+            day,
+            part,
+            input,
+            interval,
+        } => {
+            let solvers = select_solvers(day, part);
+            if solvers.is_empty() {
+                eprintln!(
+                    "No solver registered for {}",
+                    describe_selection(Some(day), part)
+                );
+                return ExitCode::FAILURE;
+            }
+            let interval = Duration::from_millis(interval);
+            let global_args = global_args(cli.strict, &cli.trace, &cli.trace_file);
+            if let Err(message) = watch::watch(&solvers, &input.source(), interval, &global_args) {
+                eprintln!("{message}");
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Batch {
+            day,
+            part,
+            dir,
+            pool,
+        } => {
+            let solvers = select_solvers(day, part);
+            if solvers.is_empty() {
+                eprintln!(
+                    "No solver registered for {}",
+                    describe_selection(Some(day), part)
+                );
+                return ExitCode::FAILURE;
+            }
+            let (files, timeout) = match (batch::input_files(&dir), pool.timeout()) {
+                (Ok(files), Ok(timeout)) => (files, timeout),
+                (Err(message), _) | (_, Err(message)) => {
+                    eprintln!("{message}");
+                    return ExitCode::FAILURE;
+                }
+            };
+            let outcomes = batch::batch(&solvers, &files, pool.jobs(), timeout);
+            let entries: Vec<history::Entry> = outcomes
+                .iter()
+                .map(|batch::FileOutcome { file, outcome }| {
+                    history::Entry::new(
+                        outcome.solver,
+                        &InputSource::Path(file.clone()),
+                        outcome.answer.as_ref(),
+                        outcome.elapsed,
+                    )
+                })
+                .collect();
+            history::record(&entries);
+            print!("{}", batch::report(&outcomes));
+            if outcomes
+                .iter()
+                .any(|file_outcome| file_outcome.outcome.answer.is_none())
+            {
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Differential {
+            cases,
+            max_size,
+            seed,
+        } => {
+            let mut failed = false;
+            for comparison in differential::COMPARISONS {
+                match comparison.smallest_disagreement(cases, max_size, seed) {
+                    Some(disagreement) => {
+                        print!("{}", comparison.describe(&disagreement));
+                        failed = true;
+                    }
+                    None => println!("{}: agree", comparison.name),
+                }
+            }
+            if failed {
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::History { day, part } => {
+            let Some(path) = history::path() else {
+                eprintln!("No history is kept, because AOC_HISTORY is empty");
+                return ExitCode::FAILURE;
+            };
+            match history::read(&path) {
+                Ok(entries) => print!("{}", history::report(&entries, day, part)),
+                Err(message) => {
+                    eprintln!("{message}");
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+        Command::List => {
+            for solver in SOLVERS {
+                println!("{}", solver_name(solver));
+            }
+        }
+        Command::Render {
+            day,
+            part,
+            input,
+            output,
+            cell_size,
+            palette,
+        } => {
+            let solvers = select_solvers(day, part);
+            if solvers.is_empty() {
+                eprintln!(
+                    "No solver registered for {}",
+                    describe_selection(Some(day), part)
+                );
+                return ExitCode::FAILURE;
+            }
+            let style = (cell_size as usize, palette.as_slice());
+            if let Err(message) = render(&solvers, &input.source(), style, &output) {
+                eprintln!("{}", message.trim_end());
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::New { day, description } => {
+            let workspace = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+            match scaffold::scaffold(workspace, day, &description) {
+                Ok(dir) => println!(
+                    "Created {}, put the example and your puzzle input in it and fill in their answers once known",
+                    dir.display()
+                ),
+                Err(message) => {
+                    eprintln!("{message}");
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+        Command::Generate { day, size, seed } => match generate::generate(day, size, seed) {
+            Some(input) => print!("{input}"),
+            None => {
+                eprintln!(
+                    "No generator for day {day}, only for days {:?}",
+                    generate::DAYS
+                );
+                return ExitCode::FAILURE;
+            }
+        },
+    }
+    ExitCode::SUCCESS
+}
+
+// The command line options override the environment variables.
+fn configure_tracing(cli: &Cli) -> Result<(), String> {
+    let spec = match &cli.trace {
+        Some(spec) => spec.clone(),
+        None => std::env::var(trace::TRACE_ENV).unwrap_or_default(),
+    };
+    let file = match &cli.trace_file {
+        Some(path) => Some(path.clone()),
+        None => std::env::var_os(trace::TRACE_FILE_ENV).map(PathBuf::from),
+    };
+    trace::configure(&spec, file.as_deref())
+}
+
+// The global options, for a runner started by this one. The trace file is made absolute, as the
+// runner may run in another directory.
+fn global_args(strict: bool, trace: &Option<String>, trace_file: &Option<PathBuf>) -> Vec<String> {
+    let mut args = Vec::new();
+    if strict {
+        args.push("--strict".to_string());
+    }
+    if let Some(spec) = trace {
+        args.extend(["--trace".to_string(), spec.clone()]);
+    }
+    if let Some(path) = trace_file {
+        let path = std::path::absolute(path).unwrap_or_else(|_| path.clone());
+        args.extend(["--trace-file".to_string(), path.display().to_string()]);
+    }
+    args
+}
+
+fn select_solvers(day: u32, part: Option<char>) -> Vec<&'static Solver> {
+    SOLVERS
+        .iter()
+        .filter(|solver| solver.day == day && part.is_none_or(|part| solver.part == part))
+        .collect()
+}
+
+fn describe_selection(day: Option<u32>, part: Option<char>) -> String {
+    match (day, part) {
+        (Some(day), Some(part)) => format!("day {day} part {part}"),
+        (Some(day), None) => format!("day {day}"),
+        _ => "any day".to_string(),
+    }
+}
+
+fn solver_name(solver: &Solver) -> String {
+    format!(
+        "Day {} part {} - {}",
+        solver.day, solver.part, solver.description
+    )
+}
+
+// Returns whether the solver produced an answer.
+fn run_solver(
+    solver: &Solver,
+    source: &InputSource,
+    format: Format,
+    stats: bool,
+    alloc: bool,
+) -> bool {
+    metrics::enable(stats);
+    metrics::take();
+    let start = Instant::now();
+    let (result, profile) = if alloc {
+        match alloc::profile_solver(solver, source) {
+            Ok((answer, profile)) => (Ok(answer), Some(profile)),
+            Err(error) => (Err(error), None),
+        }
+    } else {
+        (solver.solve_input(source), None)
+    };
+    let elapsed = start.elapsed();
+    let metrics = metrics::take();
+    history::record(&[history::Entry::new(
+        solver,
+        source,
+        result.as_ref().ok(),
+        elapsed,
+    )]);
+    match (format, &result) {
+        (Format::Text, Ok(answer)) => {
+            println!("{}: {answer}", solver_name(solver));
+            for (name, value) in &metrics {
+                println!("  {name}: {value}");
+            }
+            if let Some(profile) = &profile {
+                println!("  parse  {}", profile.parse);
+                println!("  solve  {}", profile.solve);
+            }
+        }
+        (Format::Text, Err(error)) => eprint!("{}: {error}", solver_name(solver)),
+        (Format::Json, _) => {
+            let mut object = json::run_object(solver, source, &result, elapsed);
+            if stats {
+                object["stats"] = json::stats_value(&metrics);
+            }
+            if let Some(profile) = &profile {
+                object["alloc"] = json::alloc_value(profile);
+            }
+            println!("{object}");
+        }
+    }
+    result.is_ok()
+}
+
+// Solvers without an animation are solved as usual.
+fn run_animations(solvers: &[&Solver], source: &InputSource, args: &AnimateArgs) -> ExitCode {
+    let options = animate::Options {
+        fps: args.fps,
+        max_frames: args.max_frames,
+    };
+    let mut failed = false;
+    for solver in solvers {
+        let start = Instant::now();
+        let result = animate::animate(solver, source, &options);
+        // Solvers without an animation are recorded by run_solver
+        let answer = match &result {
+            Ok(Some(played)) => Some(&played.answer),
+            Ok(None) | Err(_) => None,
+        };
+        if !matches!(result, Ok(None)) {
+            history::record(&[history::Entry::new(solver, source, answer, start.elapsed())]);
+        }
+        match result {
+            Ok(Some(played)) => {
+                let stopped = if played.stopped { ", stopped" } else { "" };
+                println!(
+                    "{}: {} ({} frames{stopped})",
+                    solver_name(solver),
+                    played.answer,
+                    played.frames
+                );
+            }
+            Ok(None) => failed |= !run_solver(solver, source, Format::Text, false, false),
+            Err(message) => {
+                eprintln!("{}: {}", solver_name(solver), message.trim_end());
+                failed = true;
+            }
+        }
+    }
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn run_bench(
+    solvers: &[&Solver],
+    source: &InputSource,
+    args: &BenchArgs,
+    format: Format,
+) -> ExitCode {
+    let baseline = args.baseline.as_deref().map(Baseline::load).transpose();
+    let saved = args
+        .save_baseline
+        .as_deref()
+        .map(Baseline::load_or_default)
+        .transpose();
+    let (baseline, mut saved) = match (baseline, saved) {
+        (Ok(baseline), Ok(saved)) => (baseline, saved),
+        (Err(message), _) | (_, Err(message)) => {
+            eprintln!("{message}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut failed = false;
+    for solver in solvers {
+        let start = Instant::now();
+        let result = time_solver(solver, source, args.repeats);
+        // The history has the median time to parse and solve, which is what a single run takes
+        let (answer, elapsed) = match &result {
+            Ok((answer, timing)) => (Some(answer), timing.parse.median + timing.solve.median),
+            Err(_) => (None, start.elapsed()),
+        };
+        history::record(&[history::Entry::new(solver, source, answer, elapsed)]);
+        let (answer, timing) = match result {
+            Ok(result) => result,
+            Err(error) => {
+                match format {
+                    Format::Text => eprint!("{}: {error}", solver_name(solver)),
+                    Format::Json => {
+                        let result = Err(error);
+                        let object = json::run_object(solver, source, &result, start.elapsed());
+                        println!("{object}");
+                    }
+                }
+                failed = true;
+                continue;
+            }
+        };
+        let regressions = baseline.as_ref().map_or_else(Vec::new, |baseline| {
+            baseline.regressions(solver, &timing, args.threshold)
+        });
+        failed |= !regressions.is_empty();
+        match format {
+            Format::Text => {
+                println!("{}: {answer}", solver_name(solver));
+                println!("  parse  {}", timing.parse);
+                println!("  solve  {}", timing.solve);
+                for regression in &regressions {
+                    println!("  REGRESSION: {regression}");
+                }
+            }
+            Format::Json => {
+                let object =
+                    json::bench_object(solver, source, answer, &timing, args.repeats, &regressions);
+                println!("{object}");
+            }
+        }
+        if let Some(saved) = &mut saved {
+            saved.insert(solver, &timing);
+        }
+    }
+    if let (Some(saved), Some(path)) = (&saved, &args.save_baseline)
+        && let Err(message) = saved.save(path)
+    {
+        eprintln!("{message}");
+        failed = true;
+    }
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn parse_palette_entry(text: &str) -> Result<(String, Rgb), String> {
+    let (kind, color) = text
+        .split_once('=')
+        .ok_or_else(|| format!("expected KIND=COLOR, not '{text}'"))?;
+    let color = Rgb::parse(color).ok_or_else(|| format!("invalid color '{color}'"))?;
+    Ok((kind.to_string(), color))
+}
+
+// Draw the picture of the first of the solvers that has one.
+fn render(
+    solvers: &[&Solver],
+    source: &InputSource,
+    (cell_size, palette): (usize, &[(String, Rgb)]),
+    output: &Path,
+) -> Result<(), String> {
+    for solver in solvers {
+        let name = solver_name(solver);
+        let input = solver
+            .read_input(source)
+            .map_err(|error| format!("{name}: {error}"))?;
+        let parsed = solver
+            .parse_input(&input, source)
+            .map_err(|error| format!("{name}: {error}"))?;
+        let Some(picture) = (solver.render)(parsed.as_ref()) else {
+            continue;
+        };
+        let colors = picture.colors(palette)?;
+        image::write_image(&colors, cell_size, output)?;
+        println!(
+            "{name}: wrote {} cells by {} to {}",
+            colors.width(),
+            colors.height(),
+            output.display()
+        );
+        return Ok(());
+    }
+    let day = solvers[0].day;
+    Err(format!("Day {day} has no picture to draw"))
+}
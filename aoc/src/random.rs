@@ -0,0 +1,51 @@
+use std::ops::RangeInclusive;
+
+// A small pseudo random number generator (SplitMix64) for generating puzzle inputs. Unlike the
+// generators of the rand crate, it is guaranteed to give the same numbers for the same seed, so a
+// seed is enough to reproduce a generated input.
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    // A number in the range, which must not be empty. The modulo bias is negligible for the small
+    // ranges that puzzle inputs need.
+    pub fn range(&mut self, range: RangeInclusive<usize>) -> usize {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "Empty range");
+        start + (self.next_u64() % (end - start + 1) as u64) as usize
+    }
+
+    // True with a chance of one in n.
+    pub fn one_in(&mut self, n: usize) -> bool {
+        self.range(1..=n) == 1
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.range(0..=items.len() - 1)]
+    }
+}
+
+#[test]
+fn test_rng() {
+    let numbers: Vec<u64> = (0..3).map(|_| Rng::new(7).next_u64()).collect();
+    assert!(numbers.iter().all(|&n| n == numbers[0]));
+    let mut rng = Rng::new(7);
+    assert_eq!(rng.next_u64(), numbers[0]);
+    assert_ne!(rng.next_u64(), numbers[0]);
+    assert!((0..1000).all(|_| (3..=5).contains(&rng.range(3..=5))));
+    assert_eq!(rng.range(4..=4), 4);
+}
@@ -0,0 +1,90 @@
+// All the solvers the runner knows about, in day and part order.
+
+use aoc_common::Solver;
+
+pub const SOLVERS: &[Solver] = &[
+    day_1::SOLVER_A,
+    day_1::SOLVER_B,
+    day_2::SOLVER_A,
+    day_2::SOLVER_B,
+    day_3::SOLVER_A,
+    day_3::SOLVER_B,
+    day_4::SOLVER_A,
+    day_4::SOLVER_B,
+    day_5::SOLVER_A,
+    day_5::SOLVER_B,
+    day_6::SOLVER_A,
+    day_6::SOLVER_B,
+    day_7::SOLVER_A,
+    day_7::SOLVER_B,
+    day_8::SOLVER_A,
+    day_8::SOLVER_B,
+    day_9::SOLVER_A,
+    day_9::SOLVER_B,
+    day_10::SOLVER_A,
+    day_10::SOLVER_B,
+    day_11::SOLVER_A,
+    day_11::SOLVER_B,
+    day_12::SOLVER_A,
+    day_12::SOLVER_B,
+    day_13::SOLVER_A,
+    day_13::SOLVER_B,
+    day_14::SOLVER_A,
+    day_14::SOLVER_B,
+    day_15::SOLVER_A,
+    day_15::SOLVER_B,
+    day_16::SOLVER_A,
+    day_16::SOLVER_B,
+    day_17::SOLVER_A,
+    day_17::SOLVER_B,
+    day_18::SOLVER_A,
+    day_18::SOLVER_B,
+    day_19::SOLVER_A,
+    day_19::SOLVER_B,
+    day_20::SOLVER_A,
+    day_21::SOLVER_A,
+];
+
+#[test]
+fn test_answers() {
+    // Each day gets its own thread, the slowest days take a while in a debug build
+    let failures: Vec<String> = std::thread::scope(|scope| {
+        let threads: Vec<_> = SOLVERS
+            .iter()
+            .map(|solver| scope.spawn(|| solver.check_answers()))
+            .collect();
+        threads
+            .into_iter()
+            .flat_map(|thread| thread.join().expect("Solver panicked"))
+            .collect()
+    });
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
+
+#[test]
+fn test_irregular_examples() {
+    // Every example with a byte order mark, Windows line endings, trailing whitespace and blank
+    // lines at the end still has the same answer
+    let path = std::env::temp_dir().join(format!("aoc_irregular_{}", std::process::id()));
+    for solver in SOLVERS {
+        let dir = std::path::Path::new(solver.dir);
+        for expected in aoc_common::read_answers(dir, solver.part).unwrap() {
+            if !expected.input.starts_with("example_input") || !expected.is_known() {
+                continue;
+            }
+            let input = std::fs::read_to_string(dir.join(&expected.input)).unwrap();
+            let irregular: String = input.lines().map(|line| format!("{line} \t\r\n")).collect();
+            std::fs::write(&path, format!("\u{feff}{irregular}\r\n \r\n")).unwrap();
+            let answer = solver.solve_input(&aoc_common::InputSource::Path(path.clone()));
+            assert_eq!(
+                answer.map(|answer| answer.to_string()).ok(),
+                Some(expected.answer),
+                "Day {} part {}, {}",
+                solver.day,
+                solver.part,
+                expected.input
+            );
+        }
+    }
+    std::fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,198 @@
+// Scaffolding: create the crate of a new day from a template, with a solver for each part that is
+// wired to the runner and a test of the example, and register it with the runner, so that it can be
+// run and checked right away.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const CARGO_TOML: &str = r#"[package]
+name = "{crate}"
+version = "0.1.0"
+edition = "2024"
+
+[dependencies]
+aoc_common.workspace = true
+"#;
+
+const LIB_RS: &str = r#"use aoc_common::{Answer, ParseError, Solution, Solver, solver};
+
+pub mod part_a;
+pub mod part_b;
+
+pub const SOLVER_A: Solver = solver! {
+    day: {day},
+    part: 'a',
+    description: {description},
+    solution: Day{day},
+};
+
+pub const SOLVER_B: Solver = solver! {
+    day: {day},
+    part: 'b',
+    description: {description},
+    solution: Day{day},
+};
+
+pub struct Day{day};
+
+impl Solution for Day{day} {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(input: &Vec<String>) -> Option<Answer> {
+        Some(part_a::solve(input).into())
+    }
+
+    fn part_b(input: &Vec<String>) -> Option<Answer> {
+        Some(part_b::solve(input).into())
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+    Ok(input.lines().map(str::to_string).collect())
+}
+"#;
+
+const PART_RS: &str = r#"pub fn solve(lines: &[String]) -> u64 {
+    lines.len() as u64
+}
+
+#[test]
+fn test_example() {
+    let input = crate::parse(include_str!("../example_input")).unwrap();
+    // The answer to the example in the puzzle
+    assert_eq!(solve(&input), 0);
+}
+"#;
+
+const MAIN_RS: &str = r#"fn main() {
+    aoc_common::run(&[{crate}::SOLVER_A, {crate}::SOLVER_B]);
+}
+"#;
+
+// The answers are filled in once they are known, until then they are not checked
+const ANSWERS: &str = "example_input:\npuzzle_input:\n";
+
+// Create the crate of the day in the workspace, and register it in the Cargo.toml and the registry
+// of the runner. Returns the directory of the crate.
+pub fn scaffold(workspace: &Path, day: u32, description: &str) -> Result<PathBuf, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("Invalid day: {day}"));
+    }
+    let name = format!("day_{day}");
+    let dir = workspace.join(&name);
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+    let cargo_toml = workspace.join("aoc/Cargo.toml");
+    let registry = workspace.join("aoc/src/registry.rs");
+    let dependency = format!("{name} = {{ path = \"../{name}\" }}");
+    let registration = format!("    {name}::SOLVER_A,\n    {name}::SOLVER_B,");
+    let cargo_toml_text = register(&read(&cargo_toml)?, &dependency, day)?;
+    let registry_text = register(&read(&registry)?, &registration, day)?;
+
+    let fill = |template: &str| {
+        template
+            .replace("{crate}", &name)
+            .replace("{day}", &day.to_string())
+            // A string literal, with quotes and backslashes escaped
+            .replace("{description}", &format!("{description:?}"))
+    };
+    let files = [
+        ("Cargo.toml", fill(CARGO_TOML)),
+        ("src/lib.rs", fill(LIB_RS)),
+        ("src/part_a.rs", PART_RS.to_string()),
+        ("src/part_b.rs", PART_RS.to_string()),
+        ("src/main.rs", fill(MAIN_RS)),
+        ("answers_a", ANSWERS.to_string()),
+        ("answers_b", ANSWERS.to_string()),
+        ("example_input", String::new()),
+        ("puzzle_input", String::new()),
+    ];
+    fs::create_dir_all(dir.join("src"))
+        .map_err(|err| format!("Cannot create {}: {err}", dir.display()))?;
+    for (file, text) in files {
+        write(&dir.join(file), &text)?;
+    }
+    write(&cargo_toml, &cargo_toml_text)?;
+    write(&registry, &registry_text)?;
+    Ok(dir)
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|err| format!("Cannot read {}: {err}", path.display()))
+}
+
+fn write(path: &Path, text: &str) -> Result<(), String> {
+    fs::write(path, text).map_err(|err| format!("Cannot write {}: {err}", path.display()))
+}
+
+// The day of a line that starts with the name of a day crate, such as day_9.
+fn day_of(line: &str) -> Option<u32> {
+    let rest = line.trim_start().strip_prefix("day_")?;
+    let end = rest
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(rest.len());
+    rest[..end].parse().ok()
+}
+
+// Insert the lines among the lines of the other day crates, in day order.
+fn register(text: &str, new_lines: &str, day: u32) -> Result<String, String> {
+    let mut lines: Vec<&str> = text.lines().collect();
+    let days: Vec<usize> = (0..lines.len())
+        .filter(|&index| day_of(lines[index]).is_some())
+        .collect();
+    if days.iter().any(|&index| day_of(lines[index]) == Some(day)) {
+        return Err(format!("day_{day} is registered already"));
+    }
+    let Some(&last) = days.last() else {
+        return Err("No day crates to register the new one with".to_string());
+    };
+    let index = days
+        .iter()
+        .copied()
+        .find(|&index| day_of(lines[index]) > Some(day))
+        .unwrap_or(last + 1);
+    lines.insert(index, new_lines);
+    Ok(lines.join("\n") + "\n")
+}
+
+#[test]
+fn test_scaffold() {
+    let workspace = std::env::temp_dir().join(format!("aoc_scaffold_{}", std::process::id()));
+    fs::create_dir_all(workspace.join("aoc/src")).unwrap();
+    fs::write(
+        workspace.join("aoc/Cargo.toml"),
+        "[dependencies]\naoc_common.workspace = true\nday_9 = { path = \"../day_9\" }\nday_11 = { path = \"../day_11\" }\n",
+    )
+    .unwrap();
+    fs::write(
+        workspace.join("aoc/src/registry.rs"),
+        "pub const SOLVERS: &[Solver] = &[\n    day_9::SOLVER_A,\n    day_9::SOLVER_B,\n    day_11::SOLVER_A,\n];\n",
+    )
+    .unwrap();
+    let dir = scaffold(&workspace, 10, "Sum of \"steps\" \\ 2").unwrap();
+    let again = scaffold(&workspace, 10, "");
+    let earlier = scaffold(&workspace, 8, "").map(|_| ());
+    let lib_rs = fs::read_to_string(dir.join("src/lib.rs")).unwrap();
+    let part_b_rs = fs::read_to_string(dir.join("src/part_b.rs")).unwrap();
+    let cargo_toml = fs::read_to_string(workspace.join("aoc/Cargo.toml")).unwrap();
+    let registry = fs::read_to_string(workspace.join("aoc/src/registry.rs")).unwrap();
+    fs::remove_dir_all(&workspace).unwrap();
+    assert!(lib_rs.contains("    part: 'b',\n    description: \"Sum of \\\"steps\\\" \\\\ 2\",\n"));
+    assert!(lib_rs.contains("        Some(part_b::solve(input).into())\n"));
+    assert!(part_b_rs.contains("pub fn solve(lines: &[String]) -> u64 {\n"));
+    assert!(again.unwrap_err().ends_with("day_10 already exists"));
+    assert_eq!(earlier, Ok(()));
+    assert_eq!(
+        cargo_toml,
+        "[dependencies]\naoc_common.workspace = true\nday_8 = { path = \"../day_8\" }\nday_9 = { path = \"../day_9\" }\nday_10 = { path = \"../day_10\" }\nday_11 = { path = \"../day_11\" }\n"
+    );
+    assert_eq!(
+        registry,
+        "pub const SOLVERS: &[Solver] = &[\n    day_8::SOLVER_A,\n    day_8::SOLVER_B,\n    day_9::SOLVER_A,\n    day_9::SOLVER_B,\n    day_10::SOLVER_A,\n    day_10::SOLVER_B,\n    day_11::SOLVER_A,\n];\n"
+    );
+}
@@ -0,0 +1,268 @@
+// Watch mode: poll the sources and inputs of a day, and when one of them changes, rebuild the runner
+// and solve the day again. Each answer is compared with the answers manifest, and its time with the
+// time of the run before.
+
+use aoc_common::{InputSource, Solver};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+// When each file was last modified, and its length, which together tell whether it changed
+type Snapshot = BTreeMap<PathBuf, (SystemTime, u64)>;
+
+// Never returns, unless the runner can't be run at all. The global args are passed on to the
+// runner, such as --strict.
+pub fn watch(
+    solvers: &[&'static Solver],
+    source: &InputSource,
+    interval: Duration,
+    global_args: &[String],
+) -> Result<(), String> {
+    let input_args = input_args(source)?;
+    let dir = Path::new(solvers[0].dir);
+    let watched = |snapshot: &mut Snapshot| {
+        snapshot_files(&dir.join("src"), true, snapshot);
+        snapshot_files(dir, false, snapshot);
+        if let Some(path) = source.path(dir) {
+            snapshot_files(&path, false, snapshot);
+        }
+    };
+    let mut snapshot = Snapshot::new();
+    watched(&mut snapshot);
+    println!(
+        "Watching {} files of {}, press Ctrl-C to stop",
+        snapshot.len(),
+        dir.display()
+    );
+    let mut previous_elapsed = HashMap::new();
+    loop {
+        run(
+            solvers,
+            source,
+            global_args,
+            &input_args,
+            &mut previous_elapsed,
+        )?;
+        // Wait for a change, and then for the files to stop changing, e.g. while an editor saves
+        let mut changed = Vec::new();
+        loop {
+            thread::sleep(interval);
+            let mut next_snapshot = Snapshot::new();
+            watched(&mut next_snapshot);
+            let changes = changed_files(&snapshot, &next_snapshot);
+            snapshot = next_snapshot;
+            if changes.is_empty() && !changed.is_empty() {
+                break;
+            }
+            changed.extend(changes);
+        }
+        changed.sort();
+        changed.dedup();
+        let names: Vec<String> = changed
+            .iter()
+            .map(|path| {
+                let path = path.strip_prefix(dir).unwrap_or(path);
+                path.display().to_string()
+            })
+            .collect();
+        println!("\nChanged: {}", names.join(", "));
+    }
+}
+
+// The arguments of the run command that read the input from the source.
+fn input_args(source: &InputSource) -> Result<Vec<String>, String> {
+    match source {
+        InputSource::Puzzle => Ok(vec![]),
+        InputSource::Example(None) => Ok(vec!["--example".to_string()]),
+        InputSource::Example(Some(number)) => Ok(vec!["--example".to_string(), number.to_string()]),
+        InputSource::Path(path) => {
+            let path = fs::canonicalize(path)
+                .map_err(|err| format!("Cannot read {}: {err}", path.display()))?;
+            Ok(vec![path.display().to_string()])
+        }
+        InputSource::Stdin => Err("Cannot watch stdin".to_string()),
+    }
+}
+
+// Add the files at the path to the snapshot: the path itself if it is a file, or the files in it if
+// it is a directory, and those in its subdirectories too if recursive.
+fn snapshot_files(path: &Path, recursive: bool, snapshot: &mut Snapshot) {
+    let Ok(metadata) = fs::metadata(path) else {
+        return;
+    };
+    if metadata.is_file() {
+        let modified = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+        snapshot.insert(path.to_path_buf(), (modified, metadata.len()));
+        return;
+    }
+    let Ok(entries) = fs::read_dir(path) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_file() || recursive {
+            snapshot_files(&path, recursive, snapshot);
+        }
+    }
+}
+
+// The files that were added, removed or changed.
+fn changed_files(before: &Snapshot, after: &Snapshot) -> Vec<PathBuf> {
+    let mut changed: Vec<PathBuf> = after
+        .iter()
+        .filter(|(path, state)| before.get(*path) != Some(state))
+        .map(|(path, _)| path.clone())
+        .collect();
+    changed.extend(
+        before
+            .keys()
+            .filter(|path| !after.contains_key(*path))
+            .cloned(),
+    );
+    changed
+}
+
+// Rebuild the runner and solve each part with it, in a process of its own, so that the new code is
+// what runs. A build error is shown by cargo, and watching goes on until the next change.
+fn run(
+    solvers: &[&'static Solver],
+    source: &InputSource,
+    global_args: &[String],
+    input_args: &[String],
+    previous_elapsed: &mut HashMap<char, Duration>,
+) -> Result<(), String> {
+    let workspace = Path::new(solvers[0].dir)
+        .parent()
+        .ok_or("The day crate is not in a workspace")?;
+    let mut args: Vec<String> = ["run", "--release", "--quiet", "-p", "aoc", "--", "run"]
+        .map(String::from)
+        .to_vec();
+    args.extend(global_args.iter().cloned());
+    args.extend(["--day".to_string(), solvers[0].day.to_string()]);
+    if let [solver] = solvers {
+        args.extend(["--part".to_string(), solver.part.to_string()]);
+    }
+    args.extend(["--format", "json"].map(String::from));
+    args.extend(input_args.iter().cloned());
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let output = Command::new(cargo)
+        .args(&args)
+        .current_dir(workspace)
+        .stdin(Stdio::null())
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|err| format!("Cannot run cargo: {err}"))?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let objects: Vec<Value> = stdout
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect();
+    if objects.is_empty() {
+        println!("No answer, see the errors above");
+    }
+    for object in objects {
+        let Some(solver) = solvers
+            .iter()
+            .find(|solver| object["part"].as_str() == Some(&solver.part.to_string()))
+        else {
+            continue;
+        };
+        let elapsed = Duration::from_nanos(object["elapsed_ns"].as_u64().unwrap_or_default());
+        let previous = previous_elapsed.insert(solver.part, elapsed);
+        println!(
+            "{}",
+            describe_run(solver, source, &object, elapsed, previous)
+        );
+    }
+    Ok(())
+}
+
+fn describe_run(
+    solver: &Solver,
+    source: &InputSource,
+    object: &Value,
+    elapsed: Duration,
+    previous: Option<Duration>,
+) -> String {
+    let name = format!(
+        "Day {} part {} - {}",
+        solver.day, solver.part, solver.description
+    );
+    let answer = match &object["answer"] {
+        Value::Null => {
+            let error = object["error"].as_str().unwrap_or_default();
+            return format!("{name}: {}", error.trim_end());
+        }
+        Value::String(text) => text.clone(),
+        answer => answer.to_string(),
+    };
+    let verdict = match solver.expected_answer(source) {
+        Ok(Some(expected)) if expected == answer => "matches the manifest".to_string(),
+        Ok(Some(expected)) => format!("WRONG, the manifest says {expected}"),
+        Ok(None) => "not in the manifest".to_string(),
+        Err(message) => message,
+    };
+    format!(
+        "{name}: {answer} ({verdict}) in {elapsed:.1?}{}",
+        describe_delta(elapsed, previous)
+    )
+}
+
+// How much faster or slower than the previous run.
+fn describe_delta(elapsed: Duration, previous: Option<Duration>) -> String {
+    let Some(previous) = previous else {
+        return String::new();
+    };
+    let percentage = |delta: Duration| 100.0 * delta.as_secs_f64() / previous.as_secs_f64();
+    if elapsed < previous {
+        let delta = previous - elapsed;
+        format!(", {delta:.1?} ({:.0}%) faster", percentage(delta))
+    } else {
+        let delta = elapsed - previous;
+        format!(", {delta:.1?} ({:.0}%) slower", percentage(delta))
+    }
+}
+
+#[test]
+fn test_changed_files() {
+    let dir = std::env::temp_dir().join(format!("aoc_watch_{}", std::process::id()));
+    fs::create_dir_all(dir.join("src/nested")).unwrap();
+    fs::write(dir.join("src/nested/a.rs"), "a").unwrap();
+    fs::write(dir.join("puzzle_input"), "1").unwrap();
+    let snapshot = |recursive| {
+        let mut snapshot = Snapshot::new();
+        snapshot_files(&dir, recursive, &mut snapshot);
+        snapshot
+    };
+    assert_eq!(snapshot(false).len(), 1);
+    let before = snapshot(true);
+    assert_eq!(before.len(), 2);
+    fs::write(dir.join("src/nested/a.rs"), "ab").unwrap();
+    fs::remove_file(dir.join("puzzle_input")).unwrap();
+    fs::write(dir.join("example_input"), "2").unwrap();
+    let mut changed = changed_files(&before, &snapshot(true));
+    changed.sort();
+    fs::remove_dir_all(&dir).unwrap();
+    assert_eq!(
+        changed,
+        ["example_input", "puzzle_input", "src/nested/a.rs"].map(|path| dir.join(path))
+    );
+}
+
+#[test]
+fn test_describe_delta() {
+    let millis = Duration::from_millis;
+    assert_eq!(describe_delta(millis(10), None), "");
+    assert_eq!(
+        describe_delta(millis(15), Some(millis(20))),
+        ", 5.0ms (25%) faster"
+    );
+    assert_eq!(
+        describe_delta(millis(30), Some(millis(20))),
+        ", 10.0ms (50%) slower"
+    );
+}
@@ -0,0 +1,61 @@
+use std::fmt;
+
+// The answer to one part of a puzzle. Most answers are numbers, but some puzzles ask for text.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Answer {
+    Int(i128),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(value) => write!(f, "{value}"),
+            Answer::Text(text) => write!(f, "{text}"),
+        }
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for Answer {
+                fn from(value: $int) -> Self {
+                    Answer::Int(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, i128);
+
+// For answers that do not fit in 64 bits. The few that do not fit in an i128 either are kept as
+// text, which prints the same.
+impl From<u128> for Answer {
+    fn from(value: u128) -> Self {
+        match i128::try_from(value) {
+            Ok(value) => Answer::Int(value),
+            Err(_) => Answer::Text(value.to_string()),
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
+
+#[test]
+fn test_wide_answers() {
+    assert_eq!(Answer::from(1u128 << 100), Answer::Int(1 << 100));
+    assert_eq!(Answer::from(u128::MAX).to_string(), u128::MAX.to_string());
+    assert_eq!(Answer::from(i128::MIN).to_string(), i128::MIN.to_string());
+}
@@ -0,0 +1,61 @@
+use std::fs::read_to_string;
+use std::path::Path;
+
+// The manifests in the directory of every day crate, answers_a and answers_b, list the expected
+// answer of each part for each of its inputs, one per line, e.g. "example_input_2: 8". An input
+// without an answer, e.g. "puzzle_input:", is one whose answer is not known yet, and is not checked.
+const ANSWERS: &str = "answers";
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExpectedAnswer {
+    // The input file, relative to the directory of the day crate
+    pub input: String,
+    pub answer: String,
+}
+
+impl ExpectedAnswer {
+    pub fn is_known(&self) -> bool {
+        !self.answer.is_empty()
+    }
+}
+
+// Read the answers manifest of the part in dir, the directory of a day crate.
+pub fn read_answers(dir: &Path, part: char) -> Result<Vec<ExpectedAnswer>, String> {
+    let path = dir.join(format!("{ANSWERS}_{part}"));
+    let text =
+        read_to_string(&path).map_err(|err| format!("Cannot read {}: {err}", path.display()))?;
+    parse_answers(&text).map_err(|line| format!("Invalid line in {}: '{line}'", path.display()))
+}
+
+// Returns the offending line if the text is not a valid manifest.
+fn parse_answers(text: &str) -> Result<Vec<ExpectedAnswer>, &str> {
+    text.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let (input, answer) = line.split_once(':').ok_or(line)?;
+            Ok(ExpectedAnswer {
+                input: input.trim().to_string(),
+                answer: answer.trim().to_string(),
+            })
+        })
+        .collect()
+}
+
+#[test]
+fn test_parse_answers() {
+    let answers = parse_answers("example_input_1: 4\n\npuzzle_input: Ab 12\n").unwrap();
+    assert_eq!(
+        answers,
+        vec![
+            ExpectedAnswer {
+                input: "example_input_1".to_string(),
+                answer: "4".to_string()
+            },
+            ExpectedAnswer {
+                input: "puzzle_input".to_string(),
+                answer: "Ab 12".to_string()
+            },
+        ]
+    );
+    assert_eq!(parse_answers("puzzle_input 4\n"), Err("puzzle_input 4"));
+}
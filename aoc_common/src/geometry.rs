@@ -0,0 +1,187 @@
+use std::fmt;
+use std::ops::{Add, AddAssign};
+
+// Positions and directions on a grid, with the same convention as Grid: x goes right (east) and y
+// goes down (south), so north is (0, -1). Positions are signed, so that a step off the grid gives a
+// position that Grid::get rejects, instead of an overflow.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Pos {
+    pub x: isize,
+    pub y: isize,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Dir {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Pos {
+    pub const fn new(x: isize, y: isize) -> Pos {
+        Pos { x, y }
+    }
+
+    // The position length steps away in the direction.
+    pub fn steps(self, dir: Dir, length: isize) -> Pos {
+        let (dx, dy) = dir.delta();
+        Pos::new(self.x + dx * length, self.y + dy * length)
+    }
+
+    pub fn manhattan(self, other: Pos) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+impl Add<Dir> for Pos {
+    type Output = Pos;
+
+    fn add(self, dir: Dir) -> Pos {
+        self.steps(dir, 1)
+    }
+}
+
+impl AddAssign<Dir> for Pos {
+    fn add_assign(&mut self, dir: Dir) {
+        *self = *self + dir;
+    }
+}
+
+// Positions on the grid, as returned by e.g. Grid::positions.
+impl From<(usize, usize)> for Pos {
+    fn from((x, y): (usize, usize)) -> Pos {
+        Pos::new(x as isize, y as isize)
+    }
+}
+
+impl Dir {
+    // Clockwise from north
+    pub const ORTHOGONAL: [Dir; 4] = [Dir::N, Dir::E, Dir::S, Dir::W];
+
+    // Clockwise from north, including the diagonals
+    pub const ALL: [Dir; 8] = [
+        Dir::N,
+        Dir::NE,
+        Dir::E,
+        Dir::SE,
+        Dir::S,
+        Dir::SW,
+        Dir::W,
+        Dir::NW,
+    ];
+
+    // The change in (x, y) of a step in the direction.
+    pub const fn delta(self) -> (isize, isize) {
+        match self {
+            Dir::N => (0, -1),
+            Dir::NE => (1, -1),
+            Dir::E => (1, 0),
+            Dir::SE => (1, 1),
+            Dir::S => (0, 1),
+            Dir::SW => (-1, 1),
+            Dir::W => (-1, 0),
+            Dir::NW => (-1, -1),
+        }
+    }
+
+    // Turn clockwise by eighths of a full turn.
+    fn rotate(self, eighths: usize) -> Dir {
+        Dir::ALL[(self as usize + eighths) % 8]
+    }
+
+    // Turn 90 degrees clockwise, e.g. from north to east.
+    pub fn turn_right(self) -> Dir {
+        self.rotate(2)
+    }
+
+    // Turn 90 degrees counterclockwise, e.g. from north to west.
+    pub fn turn_left(self) -> Dir {
+        self.rotate(6)
+    }
+
+    pub fn reverse(self) -> Dir {
+        self.rotate(4)
+    }
+
+    // The direction after bouncing off a mirror: '/' turns east into north, and '\' turns east
+    // into south.
+    pub fn reflect(self, mirror: char) -> Dir {
+        match (mirror, self.is_horizontal()) {
+            ('/', true) | ('\\', false) => self.turn_left(),
+            ('/', false) | ('\\', true) => self.turn_right(),
+            _ => panic!("Unknown mirror {mirror}"),
+        }
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Dir::E | Dir::W)
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Dir::N | Dir::S)
+    }
+}
+
+impl fmt::Display for Dir {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+// Twice the area of the polygon with these corners, in order, by the shoelace formula. The area of
+// a polygon with its corners on the grid is a multiple of a half, so twice the area is exact.
+pub fn shoelace_double_area(corners: &[Pos]) -> isize {
+    let mut double_area = 0;
+    for (index, corner) in corners.iter().enumerate() {
+        let next = corners[(index + 1) % corners.len()];
+        double_area += corner.x * next.y - next.x * corner.y;
+    }
+    double_area.abs()
+}
+
+#[test]
+fn test_turns() {
+    assert_eq!(Dir::N.turn_right(), Dir::E);
+    assert_eq!(Dir::W.turn_right(), Dir::N);
+    assert_eq!(Dir::N.turn_left(), Dir::W);
+    assert_eq!(Dir::NE.reverse(), Dir::SW);
+    for dir in Dir::ALL {
+        assert_eq!(dir.turn_left().turn_right(), dir);
+        let (dx, dy) = dir.delta();
+        assert_eq!(dir.reverse().delta(), (-dx, -dy));
+    }
+}
+
+#[test]
+fn test_reflect() {
+    assert_eq!(Dir::E.reflect('/'), Dir::N);
+    assert_eq!(Dir::N.reflect('/'), Dir::E);
+    assert_eq!(Dir::W.reflect('/'), Dir::S);
+    assert_eq!(Dir::E.reflect('\\'), Dir::S);
+    assert_eq!(Dir::N.reflect('\\'), Dir::W);
+    for dir in Dir::ORTHOGONAL {
+        assert_eq!(dir.reflect('/').reflect('/'), dir);
+    }
+}
+
+#[test]
+fn test_pos() {
+    let pos = Pos::new(2, 3);
+    assert_eq!(pos + Dir::N, Pos::new(2, 2));
+    assert_eq!(pos.steps(Dir::SE, 2), Pos::new(4, 5));
+    assert_eq!(pos.manhattan(Pos::new(-1, 5)), 5);
+    assert_eq!(Pos::from((4, 1)), Pos::new(4, 1));
+}
+
+#[test]
+fn test_shoelace() {
+    let square = [(0, 0), (3, 0), (3, 3), (0, 3)].map(|(x, y)| Pos::new(x, y));
+    assert_eq!(shoelace_double_area(&square), 18);
+    let triangle = [(0, 0), (1, 0), (0, 1)].map(|(x, y)| Pos::new(x, y));
+    assert_eq!(shoelace_double_area(&triangle), 1);
+}
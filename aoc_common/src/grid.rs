@@ -0,0 +1,315 @@
+use crate::geometry::Pos;
+use crate::parse::ParseError;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+// A rectangular grid of cells, stored row by row in a single vector. (0, 0) is the top-left cell,
+// x goes right and y goes down.
+//
+// Positions that are known to be on the grid are (usize, usize), e.g. for indexing with grid[(x, y)].
+// Positions that may be off the grid, e.g. after taking a step, are (isize, isize) and are checked
+// with get and get_mut. A Pos can index the grid too, when it is known to be on it.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+const NEIGHBOURS_4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+const NEIGHBOURS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+];
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_cells(width, height, vec![value; width * height])
+    }
+
+    pub fn from_cells(width: usize, height: usize, cells: Vec<T>) -> Grid<T> {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "Wrong number of cells for grid"
+        );
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    // Parse a grid with one row per line, mapping each character to a cell with f.
+    pub fn parse(input: &str, mut f: impl FnMut(char) -> T) -> Result<Grid<T>, ParseError> {
+        Grid::try_parse_lines(input.lines(), "cell", |c| Some(f(c)))
+    }
+
+    // Like parse, but f returns None for characters that are not a valid cell, which are reported
+    // as an unknown what, e.g. "tile".
+    pub fn try_parse(
+        input: &str,
+        what: &'static str,
+        f: impl FnMut(char) -> Option<T>,
+    ) -> Result<Grid<T>, ParseError> {
+        Grid::try_parse_lines(input.lines(), what, f)
+    }
+
+    // Like try_parse, for some lines of the input, e.g. a block.
+    pub fn try_parse_lines<'a>(
+        lines: impl IntoIterator<Item = &'a str>,
+        what: &'static str,
+        mut f: impl FnMut(char) -> Option<T>,
+    ) -> Result<Grid<T>, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for line in lines {
+            for (index, c) in line.char_indices() {
+                let cell = f(c)
+                    .ok_or_else(|| ParseError::unknown(what, &line[index..index + c.len_utf8()]))?;
+                cells.push(cell);
+            }
+            let line_width = line.chars().count();
+            match width {
+                None => width = Some(line_width),
+                Some(width) if width != line_width => {
+                    return Err(ParseError::wrong_length(width, line));
+                }
+                Some(_) => (),
+            }
+            height += 1;
+        }
+        Ok(Grid::from_cells(width.unwrap_or(0), height, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, x: isize, y: isize) -> bool {
+        x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height
+    }
+
+    fn index_of(&self, x: isize, y: isize) -> Option<usize> {
+        if self.contains(x, y) {
+            Some(y as usize * self.width + x as usize)
+        } else {
+            None
+        }
+    }
+
+    pub fn get(&self, x: isize, y: isize) -> Option<&T> {
+        self.index_of(x, y).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, x: isize, y: isize) -> Option<&mut T> {
+        self.index_of(x, y).map(|index| &mut self.cells[index])
+    }
+
+    // The up to 4 positions on the grid above, right of, below and left of (x, y).
+    pub fn neighbours_4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbours(x, y, &NEIGHBOURS_4)
+    }
+
+    // The up to 8 positions on the grid around (x, y), including the diagonals.
+    pub fn neighbours_8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbours(x, y, &NEIGHBOURS_8)
+    }
+
+    fn neighbours<'a>(
+        &'a self,
+        x: usize,
+        y: usize,
+        deltas: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        deltas.iter().filter_map(move |(dx, dy)| {
+            let (nx, ny) = (x as isize + dx, y as isize + dy);
+            self.contains(nx, ny).then_some((nx as usize, ny as usize))
+        })
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "Column {x} is off the grid");
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    // All cells, row by row.
+    pub fn cells(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    // All positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    // The position of the first cell, row by row, for which pred is true.
+    pub fn position(&self, pred: impl Fn(&T) -> bool) -> Option<(usize, usize)> {
+        let index = self.cells.iter().position(pred)?;
+        Some((index % self.width, index / self.width))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::from_cells(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+
+    // Mirror the grid in its top-left to bottom-right diagonal, so rows become columns.
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let cells = (0..self.width)
+            .flat_map(|x| self.column(x).cloned())
+            .collect();
+        Grid::from_cells(self.height, self.width, cells)
+    }
+
+    pub fn rotate_clockwise(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let cells = (0..self.width)
+            .flat_map(|x| (0..self.height).rev().map(move |y| self[(x, y)].clone()))
+            .collect();
+        Grid::from_cells(self.height, self.width, cells)
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let cells = (0..self.width)
+            .rev()
+            .flat_map(|x| (0..self.height).map(move |y| self[(x, y)].clone()))
+            .collect();
+        Grid::from_cells(self.height, self.width, cells)
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        assert!(
+            x < self.width && y < self.height,
+            "({x}, {y}) is off the grid"
+        );
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        assert!(
+            x < self.width && y < self.height,
+            "({x}, {y}) is off the grid"
+        );
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos.x, pos.y)
+            .unwrap_or_else(|| panic!("{pos:?} is off the grid"))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        self.get_mut(pos.x, pos.y)
+            .unwrap_or_else(|| panic!("{pos:?} is off the grid"))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_parse() {
+    let grid = Grid::parse("12\n34\n56\n", |c| c.to_digit(10).unwrap()).unwrap();
+    assert_eq!((grid.width(), grid.height()), (2, 3));
+    assert_eq!(grid[(1, 0)], 2);
+    assert_eq!(grid.get(0, 2), Some(&5));
+    assert_eq!(grid.get(2, 0), None);
+    assert_eq!(grid.get(0, -1), None);
+    assert_eq!(grid.row(1), &[3, 4]);
+    assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 4, 6]);
+    assert_eq!(grid.to_string(), "12\n34\n56\n");
+}
+
+#[test]
+fn test_parse_errors() {
+    let error = Grid::parse("..\n...\n", |c| c).unwrap_err();
+    assert_eq!(error.text, "...");
+    let error = Grid::try_parse(".#\n#?\n", "tile", |c| (c != '?').then_some(c)).unwrap_err();
+    assert_eq!(error.text, "?");
+}
+
+#[test]
+fn test_neighbours() {
+    let grid = Grid::new(3, 3, '.');
+    assert_eq!(grid.neighbours_4(1, 1).count(), 4);
+    assert_eq!(grid.neighbours_8(1, 1).count(), 8);
+    assert_eq!(
+        grid.neighbours_4(0, 0).collect::<Vec<_>>(),
+        vec![(1, 0), (0, 1)]
+    );
+    assert_eq!(grid.neighbours_8(2, 2).count(), 3);
+}
+
+#[test]
+fn test_transpose_and_rotate() {
+    let grid = Grid::parse("abc\ndef\n", |c| c).unwrap();
+    assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+    assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc\n");
+    assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad\n");
+    assert_eq!(
+        grid.rotate_clockwise().rotate_counterclockwise(),
+        grid.clone()
+    );
+    assert_eq!(grid.position(|&c| c == 'e'), Some((1, 1)));
+}
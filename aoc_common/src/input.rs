@@ -0,0 +1,271 @@
+use crate::parse::ParseError;
+use std::fs::read_to_string;
+use std::io::{Read, stdin};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+// The name of the input file that every day crate reads from its own directory.
+pub(crate) const PUZZLE_INPUT: &str = "puzzle_input";
+
+// The name of the example input file(s) next to the puzzle input. Days with more than one example
+// number them: example_input_1, example_input_2, ...
+const EXAMPLE_INPUT: &str = "example_input";
+
+// Stdin can only be read once, so it is kept for the other solvers that read it, e.g. both parts
+// of a day.
+static STDIN: OnceLock<Result<String, String>> = OnceLock::new();
+
+// Where to read the input of a solver from.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub enum InputSource {
+    // The puzzle_input in the directory of the day crate
+    #[default]
+    Puzzle,
+    // The example input in the directory of the day crate, optionally with its number
+    Example(Option<u32>),
+    // Any file, relative to the current directory
+    Path(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    // Parse the command line arguments of a day binary: [PATH | - | --example [N]]
+    pub fn from_args(args: &[String]) -> Result<InputSource, String> {
+        match args {
+            [] => Ok(InputSource::Puzzle),
+            [flag] if flag == "--example" => Ok(InputSource::Example(None)),
+            [flag, number] if flag == "--example" => match number.parse() {
+                Ok(number) => Ok(InputSource::Example(Some(number))),
+                Err(_) => Err(format!("Invalid example number: {number}")),
+            },
+            [flag, ..] if flag.starts_with("--") => Err(format!("Unknown option: {flag}")),
+            [path] if path == "-" => Ok(InputSource::Stdin),
+            [path] => Ok(InputSource::Path(PathBuf::from(path))),
+            _ => Err("Expected at most one input".to_string()),
+        }
+    }
+
+    // The file to read, where dir is the directory of the day crate. None means stdin.
+    pub fn path(&self, dir: &Path) -> Option<PathBuf> {
+        match self {
+            InputSource::Puzzle => Some(dir.join(PUZZLE_INPUT)),
+            InputSource::Example(number) => Some(example_path(dir, *number)),
+            InputSource::Path(path) => Some(path.clone()),
+            InputSource::Stdin => None,
+        }
+    }
+
+    pub fn read(&self, dir: &Path) -> Result<String, String> {
+        match self.path(dir) {
+            Some(path) => read_to_string(&path)
+                .map_err(|err| format!("Cannot read {}: {err}", path.display())),
+            None => STDIN
+                .get_or_init(|| {
+                    let mut input = String::new();
+                    stdin()
+                        .read_to_string(&mut input)
+                        .map_err(|err| format!("Cannot read stdin: {err}"))?;
+                    Ok(input)
+                })
+                .clone(),
+        }
+    }
+}
+
+// Days with a single example call it example_input, days with several number them from 1. Accept
+// either name for the first example, so --example always works and --example 1 always works.
+fn example_path(dir: &Path, number: Option<u32>) -> PathBuf {
+    let unnumbered = dir.join(EXAMPLE_INPUT);
+    let first = dir.join(format!("{EXAMPLE_INPUT}_1"));
+    match number {
+        None | Some(1) if unnumbered.exists() => unnumbered,
+        None | Some(1) => first,
+        Some(number) => dir.join(format!("{EXAMPLE_INPUT}_{number}")),
+    }
+}
+
+// Inputs that were saved on another system or edited by hand can have a byte order mark, Windows
+// line endings, trailing whitespace on their lines, or blank lines at the end or no newline at all.
+// Parsers should not have to deal with that, so the input is normalized before it is parsed: the
+// byte order mark and the whitespace at the end of each line are removed, and the input ends in a
+// single newline. In strict mode, the input is not normalized, and any of these is an error instead.
+static STRICT: AtomicBool = AtomicBool::new(false);
+
+pub fn set_strict(strict: bool) {
+    STRICT.store(strict, Ordering::Relaxed);
+}
+
+pub fn is_strict() -> bool {
+    STRICT.load(Ordering::Relaxed)
+}
+
+const BYTE_ORDER_MARK: char = '\u{feff}';
+
+pub fn normalize(input: &str) -> String {
+    let input = input.strip_prefix(BYTE_ORDER_MARK).unwrap_or(input);
+    let mut normalized = String::with_capacity(input.len() + 1);
+    for line in input.lines() {
+        normalized += line.trim_end();
+        normalized.push('\n');
+    }
+    let end = normalized.trim_end_matches('\n').len();
+    normalized.truncate(end);
+    if !normalized.is_empty() {
+        normalized.push('\n');
+    }
+    normalized
+}
+
+// The first thing that normalize would change in the input, as an error that points at it.
+pub fn check_regular(input: &str) -> Result<(), ParseError> {
+    if input.starts_with(BYTE_ORDER_MARK) {
+        return Err(ParseError::irregular(
+            "byte order mark",
+            &input[..BYTE_ORDER_MARK.len_utf8()],
+        ));
+    }
+    for line in input.split_inclusive('\n') {
+        let content = line.strip_suffix('\n').unwrap_or(line);
+        if let Some(carriage_return) = content.strip_suffix('\r') {
+            let offset = carriage_return.len();
+            return Err(ParseError::irregular(
+                "Windows line ending",
+                &content[offset..],
+            ));
+        }
+        let trimmed = content.trim_end();
+        if trimmed.len() < content.len() {
+            return Err(ParseError::irregular(
+                "trailing whitespace",
+                &content[trimmed.len()..],
+            ));
+        }
+    }
+    let end = input.trim_end_matches('\n').len();
+    if !input.is_empty() && !input.ends_with('\n') {
+        return Err(ParseError::irregular(
+            "no newline at the end",
+            &input[end..],
+        ));
+    }
+    if input.len() > end + 1 {
+        return Err(ParseError::irregular(
+            "blank lines at the end",
+            &input[end + 1..],
+        ));
+    }
+    Ok(())
+}
+
+// Split the input into blocks of lines, where blocks are separated by one or more blank lines.
+// Leading and trailing whitespace is removed from each line.
+pub fn blocks(input: &str) -> Vec<Vec<&str>> {
+    let mut blocks = Vec::new();
+    let mut block = Vec::new();
+    for line in input.lines().map(str::trim) {
+        if line.is_empty() {
+            if !block.is_empty() {
+                blocks.push(block);
+                block = Vec::new();
+            }
+        } else {
+            block.push(line);
+        }
+    }
+    if !block.is_empty() {
+        blocks.push(block);
+    }
+    blocks
+}
+
+#[test]
+fn test_blocks() {
+    let input = "a\nb\n\nc\n\n\nd\ne\n";
+    assert_eq!(
+        blocks(input),
+        vec![vec!["a", "b"], vec!["c"], vec!["d", "e"]]
+    );
+}
+
+#[test]
+fn test_blocks_crlf_and_trailing_blank_lines() {
+    let input = "a\r\n\r\nb\r\n\r\n";
+    assert_eq!(blocks(input), vec![vec!["a"], vec!["b"]]);
+}
+
+#[test]
+fn test_input_source_from_args() {
+    let args = |args: &[&str]| -> Vec<String> { args.iter().map(|arg| arg.to_string()).collect() };
+    assert_eq!(InputSource::from_args(&args(&[])), Ok(InputSource::Puzzle));
+    assert_eq!(
+        InputSource::from_args(&args(&["-"])),
+        Ok(InputSource::Stdin)
+    );
+    assert_eq!(
+        InputSource::from_args(&args(&["test_input"])),
+        Ok(InputSource::Path(PathBuf::from("test_input")))
+    );
+    assert_eq!(
+        InputSource::from_args(&args(&["--example"])),
+        Ok(InputSource::Example(None))
+    );
+    assert_eq!(
+        InputSource::from_args(&args(&["--example", "2"])),
+        Ok(InputSource::Example(Some(2)))
+    );
+    assert!(InputSource::from_args(&args(&["--example", "two"])).is_err());
+    assert!(InputSource::from_args(&args(&["--bogus"])).is_err());
+}
+
+#[test]
+fn test_example_path() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../day_10");
+    assert_eq!(example_path(&dir, None), dir.join("example_input_1"));
+    assert_eq!(example_path(&dir, Some(2)), dir.join("example_input_2"));
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../day_17");
+    assert_eq!(example_path(&dir, None), dir.join("example_input"));
+    assert_eq!(example_path(&dir, Some(1)), dir.join("example_input"));
+}
+
+#[test]
+fn test_normalize() {
+    assert_eq!(normalize("\u{feff}a b \r\n\t\r\nc\t\n\n \n"), "a b\n\nc\n");
+    assert_eq!(normalize("a\nb"), "a\nb\n");
+    assert_eq!(normalize("a\nb\n"), "a\nb\n");
+    assert_eq!(normalize(" a\n"), " a\n");
+    assert_eq!(normalize("\n\n"), "");
+}
+
+#[test]
+fn test_check_regular() {
+    let irregularity = |input: &str| {
+        let error = check_regular(input).unwrap_err().locate(input, None);
+        (error.to_string(), error.column)
+    };
+    assert_eq!(check_regular("a b\n\nc\n"), Ok(()));
+    assert_eq!(check_regular(""), Ok(()));
+    assert_eq!(
+        irregularity("\u{feff}a\n"),
+        ("1:1: irregular input: byte order mark".to_string(), 1)
+    );
+    assert_eq!(
+        irregularity("a\nbc\r\n"),
+        ("2:3: irregular input: Windows line ending".to_string(), 3)
+    );
+    assert_eq!(
+        irregularity("a  \n"),
+        ("1:2: irregular input: trailing whitespace".to_string(), 2)
+    );
+    assert_eq!(
+        irregularity("a\nb"),
+        ("2:2: irregular input: no newline at the end".to_string(), 2)
+    );
+    assert_eq!(
+        irregularity("a\n\n\n"),
+        (
+            "2:1: irregular input: blank lines at the end".to_string(),
+            1
+        )
+    );
+}
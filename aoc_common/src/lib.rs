@@ -0,0 +1,26 @@
+// Plumbing shared by all the day crates: reading the input (puzzle, example, any file or stdin),
+// normalizing its line endings and whitespace, splitting it into lines and blank-line separated
+// blocks, parsing grids, positions and directions on them, reporting parse errors with their
+// location in the input, registering solvers with the runner, reading the expected answers of each
+// day, drawing pictures of the state of a puzzle, or animating it step by step, and tracing and
+// measuring what a solution does.
+
+mod answer;
+mod answers;
+mod geometry;
+mod grid;
+mod input;
+pub mod metrics;
+mod parse;
+mod picture;
+mod solver;
+pub mod trace;
+
+pub use answer::Answer;
+pub use answers::{ExpectedAnswer, read_answers};
+pub use geometry::{Dir, Pos, shoelace_double_area};
+pub use grid::Grid;
+pub use input::{InputSource, blocks, check_regular, normalize, set_strict};
+pub use parse::{ParseError, ParseErrorKind, only_chars, parse_number, split_once, strip_prefix};
+pub use picture::{Animation, Paint, Picture, Rgb};
+pub use solver::{ASSUMPTION_FAILED, Error, Solution, Solver, assume, run};
@@ -0,0 +1,130 @@
+// Metrics that solvers update to show where their time goes, e.g. how many states a search explored
+// or how often a cache had the answer. A metric is a count, or the maximum of a value such as the
+// depth of a recursion. Metrics are kept per thread, so that solvers that run at the same time on
+// different threads don't mix them up. They are off until enabled, and then a solver updates them
+// with a single atomic load while they are off.
+
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Kind {
+    Count,
+    Max,
+}
+
+thread_local! {
+    // The metrics in the order in which they were first updated
+    static METRICS: RefCell<Vec<(&'static str, Kind, u64)>> = const { RefCell::new(Vec::new()) };
+    // The current depth of each recursion that is measured with enter
+    static DEPTHS: RefCell<Vec<(&'static str, u64)>> = const { RefCell::new(Vec::new()) };
+}
+
+pub fn enable(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+#[inline]
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+// Add n to the count.
+#[inline]
+pub fn count(name: &'static str, n: u64) {
+    if enabled() {
+        update(name, Kind::Count, n);
+    }
+}
+
+// Keep the largest value.
+#[inline]
+pub fn record_max(name: &'static str, value: u64) {
+    if enabled() {
+        update(name, Kind::Max, value);
+    }
+}
+
+fn update(name: &'static str, kind: Kind, value: u64) {
+    METRICS.with_borrow_mut(
+        |metrics| match metrics.iter_mut().find(|metric| metric.0 == name) {
+            Some((_, Kind::Count, total)) => *total += value,
+            Some((_, Kind::Max, max)) => *max = (*max).max(value),
+            None => metrics.push((name, kind, value)),
+        },
+    );
+}
+
+// Measure the depth of a recursion: the recursive function calls enter, and the maximum depth is
+// the metric. The depth goes back down when the guard is dropped, at the end of the call.
+#[inline]
+pub fn enter(name: &'static str) -> DepthGuard {
+    DepthGuard {
+        name: enabled().then(|| {
+            let depth = change_depth(name, 1);
+            update(name, Kind::Max, depth);
+            name
+        }),
+    }
+}
+
+pub struct DepthGuard {
+    name: Option<&'static str>,
+}
+
+impl Drop for DepthGuard {
+    fn drop(&mut self) {
+        if let Some(name) = self.name {
+            change_depth(name, -1);
+        }
+    }
+}
+
+fn change_depth(name: &'static str, change: i64) -> u64 {
+    DEPTHS.with_borrow_mut(|depths| {
+        let index = match depths.iter().position(|depth| depth.0 == name) {
+            Some(index) => index,
+            None => {
+                depths.push((name, 0));
+                depths.len() - 1
+            }
+        };
+        let depth = &mut depths[index].1;
+        *depth = depth.saturating_add_signed(change);
+        *depth
+    })
+}
+
+// The metrics of this thread since the last take, which starts them over.
+pub fn take() -> Vec<(&'static str, u64)> {
+    DEPTHS.with_borrow_mut(Vec::clear);
+    METRICS
+        .take()
+        .into_iter()
+        .map(|(name, _, value)| (name, value))
+        .collect()
+}
+
+#[test]
+fn test_metrics() {
+    fn recurse(levels: u64) {
+        let _depth = enter("depth");
+        count("calls", 1);
+        if levels > 0 {
+            recurse(levels - 1);
+            recurse(levels - 1);
+        }
+    }
+
+    enable(true);
+    take();
+    recurse(3);
+    record_max("largest", 2);
+    record_max("largest", 7);
+    record_max("largest", 5);
+    count("calls", 0);
+    assert_eq!(take(), vec![("depth", 4), ("calls", 15), ("largest", 7)]);
+    assert_eq!(take(), vec![]);
+}
@@ -0,0 +1,196 @@
+use std::fmt;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+// What was wrong with the input.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseErrorKind {
+    // Text that must be there is missing, e.g. Expected(":")
+    Expected(&'static str),
+    // A field that must be there is missing, e.g. Missing("length")
+    Missing(&'static str),
+    InvalidNumber,
+    // The text should have been this many characters long, e.g. a row of a grid
+    WrongLength(usize),
+    // The text is not one of the allowed values, e.g. Unknown("module type")
+    Unknown(&'static str),
+    // The input is not in the regular form that strict mode requires, e.g. Irregular("trailing
+    // whitespace")
+    Irregular(&'static str),
+}
+
+// An error in the input, with the offending text and where it was found.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    pub kind: ParseErrorKind,
+    pub text: String,
+    pub file: Option<PathBuf>,
+    // Line and column are counted from 1, and are 0 if the error has not been located
+    pub line: usize,
+    pub column: usize,
+    // The whole line of the input that contains the error
+    source_line: String,
+    // Where the offending text is in memory, see locate
+    address: usize,
+}
+
+impl ParseError {
+    pub fn new(kind: ParseErrorKind, text: &str) -> ParseError {
+        ParseError {
+            kind,
+            text: text.to_string(),
+            file: None,
+            line: 0,
+            column: 0,
+            source_line: String::new(),
+            address: text.as_ptr() as usize,
+        }
+    }
+
+    pub fn expected(what: &'static str, text: &str) -> ParseError {
+        ParseError::new(ParseErrorKind::Expected(what), text)
+    }
+
+    pub fn missing(what: &'static str, text: &str) -> ParseError {
+        ParseError::new(ParseErrorKind::Missing(what), text)
+    }
+
+    pub fn invalid_number(text: &str) -> ParseError {
+        ParseError::new(ParseErrorKind::InvalidNumber, text)
+    }
+
+    pub fn wrong_length(expected: usize, text: &str) -> ParseError {
+        ParseError::new(ParseErrorKind::WrongLength(expected), text)
+    }
+
+    pub fn unknown(what: &'static str, text: &str) -> ParseError {
+        ParseError::new(ParseErrorKind::Unknown(what), text)
+    }
+
+    pub fn irregular(what: &'static str, text: &str) -> ParseError {
+        ParseError::new(ParseErrorKind::Irregular(what), text)
+    }
+
+    // Parsers only get to see slices of the input, so they can't know the line and column of an
+    // error themselves. Instead, the error remembers where its text is in memory, and if that is
+    // inside the input, this works out the line and column from there (like nom's Offset trait).
+    pub fn locate(mut self, input: &str, file: Option<PathBuf>) -> ParseError {
+        let start = input.as_ptr() as usize;
+        if self.address >= start && self.address <= start + input.len() {
+            let offset = self.address - start;
+            let before = &input[..offset];
+            let line_start = before.rfind('\n').map_or(0, |pos| pos + 1);
+            self.line = before.matches('\n').count() + 1;
+            self.column = before[line_start..].chars().count() + 1;
+            self.source_line = input[line_start..].lines().next().unwrap_or("").to_string();
+        }
+        self.file = file;
+        self
+    }
+
+    fn message(&self) -> String {
+        let found = if self.text.is_empty() {
+            String::new()
+        } else {
+            format!(" in '{}'", self.text)
+        };
+        match self.kind {
+            ParseErrorKind::Expected(what) => format!("expected '{what}'{found}"),
+            ParseErrorKind::Missing(what) => format!("missing {what}{found}"),
+            ParseErrorKind::InvalidNumber => format!("invalid number '{}'", self.text),
+            ParseErrorKind::WrongLength(expected) => {
+                format!("expected {expected} characters in '{}'", self.text)
+            }
+            ParseErrorKind::Unknown(what) => format!("unknown {what} '{}'", self.text),
+            ParseErrorKind::Irregular(what) => format!("irregular input: {what}"),
+        }
+    }
+
+    // A compiler style diagnostic, which shows the line of the input with the error underlined.
+    pub fn diagnostic(&self) -> String {
+        let mut diagnostic = format!("error: {}\n", self.message());
+        let file = self
+            .file
+            .as_ref()
+            .map_or("<stdin>".to_string(), |file| file.display().to_string());
+        if self.line == 0 {
+            diagnostic += &format!(" --> {file}\n");
+            return diagnostic;
+        }
+        let margin = " ".repeat(self.line.to_string().len());
+        let underline = "^".repeat(self.text.chars().count().max(1));
+        diagnostic += &format!("{margin}--> {file}:{}:{}\n", self.line, self.column);
+        diagnostic += &format!("{margin} |\n");
+        diagnostic += &format!("{} | {}\n", self.line, self.source_line);
+        diagnostic += &format!("{margin} | {}{underline}\n", " ".repeat(self.column - 1));
+        diagnostic
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file.display())?;
+        }
+        if self.line != 0 {
+            write!(f, "{}:{}:", self.line, self.column)?;
+        }
+        if self.file.is_some() || self.line != 0 {
+            write!(f, " ")?;
+        }
+        write!(f, "{}", self.message())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+pub fn parse_number<T: FromStr>(text: &str) -> Result<T, ParseError> {
+    let text = text.trim();
+    text.parse().map_err(|_| ParseError::invalid_number(text))
+}
+
+pub fn split_once<'a>(
+    text: &'a str,
+    delimiter: &'static str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    text.split_once(delimiter)
+        .ok_or_else(|| ParseError::expected(delimiter, text))
+}
+
+pub fn strip_prefix<'a>(text: &'a str, prefix: &'static str) -> Result<&'a str, ParseError> {
+    text.strip_prefix(prefix)
+        .ok_or_else(|| ParseError::expected(prefix, text))
+}
+
+// Check that the text only has the allowed characters, e.g. only_chars(hand, "23456789TJQKA", "card").
+pub fn only_chars(text: &str, allowed: &str, what: &'static str) -> Result<(), ParseError> {
+    match text.char_indices().find(|&(_, c)| !allowed.contains(c)) {
+        Some((index, c)) => Err(ParseError::unknown(
+            what,
+            &text[index..index + c.len_utf8()],
+        )),
+        None => Ok(()),
+    }
+}
+
+#[test]
+fn test_locate() {
+    let input = "Card 1: 1 2 | 3\nCard 2: 4 x | 6\n";
+    let line = input.lines().nth(1).unwrap();
+    let error = parse_number::<usize>(&line[10..11]).unwrap_err();
+    let error = error.locate(input, Some(PathBuf::from("example_input")));
+    assert_eq!(error.kind, ParseErrorKind::InvalidNumber);
+    assert_eq!((error.line, error.column), (2, 11));
+    assert_eq!(error.to_string(), "example_input:2:11: invalid number 'x'");
+    assert_eq!(
+        error.diagnostic(),
+        "error: invalid number 'x'\n --> example_input:2:11\n  |\n2 | Card 2: 4 x | 6\n  |           ^\n"
+    );
+}
+
+#[test]
+fn test_locate_text_outside_input() {
+    let error = ParseError::unknown("direction", "X").locate("U 1 (#000000)", None);
+    assert_eq!((error.line, error.column), (0, 0));
+    assert_eq!(error.to_string(), "unknown direction 'X'");
+}
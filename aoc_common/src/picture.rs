@@ -0,0 +1,131 @@
+use crate::grid::Grid;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    // Parse a hex color like #70c710, with or without the #.
+    pub fn parse(text: &str) -> Option<Rgb> {
+        let hex = text.strip_prefix('#').unwrap_or(text);
+        if hex.len() != 6 || !hex.is_ascii() {
+            return None;
+        }
+        let component = |index: usize| u8::from_str_radix(&hex[index..index + 2], 16).ok();
+        Some(Rgb(component(0)?, component(2)?, component(4)?))
+    }
+}
+
+// What a cell of a picture is painted with.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Paint {
+    // One of the kinds of cell of the day, e.g. "inside", which gets its color from the palette
+    Kind(&'static str),
+    // A color that comes from the input itself, e.g. the trench colors of day 18
+    Color(Rgb),
+}
+
+// A picture of the state of a puzzle, e.g. after solving it, which the runner can write as an image.
+#[derive(Clone, Debug)]
+pub struct Picture {
+    pub cells: Grid<Paint>,
+    // Every kind of cell that the picture can have, with its default color
+    pub palette: Vec<(&'static str, Rgb)>,
+}
+
+impl Picture {
+    // The color of each cell, where the colors of kinds in overrides replace their default colors.
+    pub fn colors(&self, overrides: &[(String, Rgb)]) -> Result<Grid<Rgb>, String> {
+        let mut palette = self.palette.clone();
+        for (kind, color) in overrides {
+            let Some(entry) = palette.iter_mut().find(|(name, _)| name == kind) else {
+                let kinds: Vec<&str> = self.palette.iter().map(|(name, _)| *name).collect();
+                return Err(format!(
+                    "Unknown kind of cell '{kind}', expected one of: {}",
+                    kinds.join(", ")
+                ));
+            };
+            entry.1 = *color;
+        }
+        let color_of = |paint: &Paint| match paint {
+            Paint::Kind(kind) => {
+                palette
+                    .iter()
+                    .find(|(name, _)| name == kind)
+                    .unwrap_or_else(|| panic!("Kind of cell '{kind}' is not in the palette"))
+                    .1
+            }
+            Paint::Color(color) => *color,
+        };
+        Ok(self.cells.map(color_of))
+    }
+}
+
+// The frames of a puzzle being solved step by step, which the runner shows in the terminal. The
+// viewer can stop the animation at any frame, after which the solution carries on without drawing
+// frames, so it still computes the same answer.
+pub struct Animation<'a> {
+    show: &'a mut dyn FnMut(Picture) -> bool,
+    stopped: bool,
+}
+
+impl<'a> Animation<'a> {
+    // Show returns whether the viewer wants to see more frames.
+    pub fn new(show: &'a mut dyn FnMut(Picture) -> bool) -> Animation<'a> {
+        Animation {
+            show,
+            stopped: false,
+        }
+    }
+
+    // Show the next frame, unless the animation was stopped. The picture is only drawn if it is shown.
+    pub fn frame(&mut self, draw: impl FnOnce() -> Picture) {
+        if !self.stopped {
+            self.stopped = !(self.show)(draw());
+        }
+    }
+}
+
+#[test]
+fn test_rgb_parse() {
+    assert_eq!(Rgb::parse("#70c710"), Some(Rgb(0x70, 0xc7, 0x10)));
+    assert_eq!(Rgb::parse("FFFFFF"), Some(Rgb(255, 255, 255)));
+    assert_eq!(Rgb::parse("#70c71"), None);
+    assert_eq!(Rgb::parse("#70c7xx"), None);
+}
+
+#[test]
+fn test_picture_colors() {
+    let red = Rgb(255, 0, 0);
+    let picture = Picture {
+        cells: Grid::from_cells(2, 1, vec![Paint::Kind("rock"), Paint::Color(red)]),
+        palette: vec![("rock", Rgb(0, 0, 0))],
+    };
+    let colors = picture.colors(&[]).unwrap();
+    assert_eq!(colors.row(0), &[Rgb(0, 0, 0), red]);
+    let white = Rgb(255, 255, 255);
+    let colors = picture.colors(&[("rock".to_string(), white)]).unwrap();
+    assert_eq!(colors[(0, 0)], white);
+    assert!(picture.colors(&[("sand".to_string(), white)]).is_err());
+}
+
+#[test]
+fn test_animation_stops() {
+    let mut shown = 0;
+    let mut show = |_picture: Picture| {
+        shown += 1;
+        shown < 2
+    };
+    let mut animation = Animation::new(&mut show);
+    let mut drawn = 0;
+    for _ in 0..5 {
+        animation.frame(|| {
+            drawn += 1;
+            Picture {
+                cells: Grid::new(1, 1, Paint::Kind("empty")),
+                palette: vec![("empty", Rgb(0, 0, 0))],
+            }
+        });
+    }
+    assert_eq!(drawn, 2);
+    assert_eq!(shown, 2);
+}
@@ -0,0 +1,370 @@
+use crate::answer::Answer;
+use crate::answers::{ExpectedAnswer, read_answers};
+use crate::input::{InputSource, PUZZLE_INPUT, check_regular, is_strict, normalize};
+use crate::parse::ParseError;
+use crate::picture::{Animation, Picture};
+use std::any::Any;
+use std::cell::Cell;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+use std::process::exit;
+use std::sync::Once;
+
+// Every day crate registers each part it solves as a solver, which the aoc runner dispatches to.
+pub struct Solver {
+    pub day: u32,
+    pub part: char,
+    // What the answer means, e.g. "Sum of calibration values"
+    pub description: &'static str,
+    // The directory of the day crate, which contains the puzzle and example inputs
+    pub dir: &'static str,
+    // Solving is split in two phases, so they can be timed separately. The parsed input is passed
+    // from one to the other as Any, so that solvers for all days fit in the same table.
+    pub parse: fn(&str) -> Result<Box<dyn Any>, ParseError>,
+    pub solve: fn(&dyn Any) -> Answer,
+    // A picture of the state of the puzzle after solving it, for the days that can draw one
+    pub render: fn(&dyn Any) -> Option<Picture>,
+    // Solve step by step, showing a picture of each step, for the days that can animate it.
+    // Returns None if the part has no animation.
+    pub animate: fn(&dyn Any, &mut Animation) -> Option<Answer>,
+}
+
+// A day of the puzzle as a library: how to parse its input, and how to solve each part from the
+// parsed input. Both parts share the parsed input. A part that is not solved (yet) is None.
+pub trait Solution {
+    type Input: 'static;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part_a(_input: &Self::Input) -> Option<Answer> {
+        None
+    }
+
+    fn part_b(_input: &Self::Input) -> Option<Answer> {
+        None
+    }
+
+    // A picture of the puzzle after solving the part
+    fn render(_input: &Self::Input, _part: char) -> Option<Picture> {
+        None
+    }
+
+    // Solve the part like part_a or part_b does, showing each step of the way in the animation.
+    fn animate(_input: &Self::Input, _part: char, _animation: &mut Animation) -> Option<Answer> {
+        None
+    }
+}
+
+// Define the solver for a part of a day, given the type that implements its Solution.
+#[macro_export]
+macro_rules! solver {
+    (day: $day:expr, part: $part:expr, description: $description:expr, solution: $solution:ty $(,)?) => {
+        $crate::Solver::new::<$solution>(
+            $day,
+            $part,
+            $description,
+            ::std::env!("CARGO_MANIFEST_DIR"),
+        )
+    };
+}
+
+// Why a solver could not produce an answer.
+#[derive(Debug)]
+pub enum Error {
+    Input(String),
+    Parse(ParseError),
+    // An arithmetic overflow in the solve phase, which is only caught in builds with overflow
+    // checks, such as the checked profile
+    Overflow(String),
+}
+
+// Some solutions rely on a property that the puzzle inputs have but that the puzzle does not promise.
+// They check it with assume, so that an input without it is reported as such instead of giving a
+// wrong answer or hanging. A failed assumption is a panic with a message that starts with
+// ASSUMPTION_FAILED.
+pub const ASSUMPTION_FAILED: &str = "Input assumption failed";
+
+#[track_caller]
+pub fn assume(holds: bool, assumption: &str) {
+    if !holds {
+        panic!("{ASSUMPTION_FAILED}: {assumption}");
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Input(message) => writeln!(f, "error: {message}"),
+            Error::Parse(error) => write!(f, "{}", error.diagnostic()),
+            Error::Overflow(message) => writeln!(f, "error: arithmetic overflow: {message}"),
+        }
+    }
+}
+
+// With overflow checks, an overflow panics with a message like "attempt to add with overflow".
+fn overflow_message(payload: &(dyn Any + Send)) -> Option<&str> {
+    let message = match payload.downcast_ref::<&str>() {
+        Some(message) => *message,
+        None => payload.downcast_ref::<String>()?.as_str(),
+    };
+    (message.starts_with("attempt to") && message.ends_with("with overflow")).then_some(message)
+}
+
+thread_local! {
+    static SOLVING: Cell<bool> = const { Cell::new(false) };
+}
+
+// The panic hook would print an overflow that solve_parsed reports as an error, so it is wrapped
+// once in a hook that skips those. The hook is shared by all threads, so whether a thread is
+// solving is kept per thread.
+fn quiet_overflows() {
+    static QUIET: Once = Once::new();
+    QUIET.call_once(|| {
+        let hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !(SOLVING.get() && overflow_message(info.payload()).is_some()) {
+                hook(info);
+            }
+        }));
+    });
+}
+
+impl Solver {
+    pub const fn new<S: Solution>(
+        day: u32,
+        part: char,
+        description: &'static str,
+        dir: &'static str,
+    ) -> Solver {
+        type Fns = (
+            fn(&dyn Any) -> Answer,
+            fn(&dyn Any) -> Option<Picture>,
+            fn(&dyn Any, &mut Animation) -> Option<Answer>,
+        );
+        let (solve, render, animate): Fns = match part {
+            'a' => (
+                solve_part_a::<S>,
+                render_any::<S, 'a'>,
+                animate_any::<S, 'a'>,
+            ),
+            'b' => (
+                solve_part_b::<S>,
+                render_any::<S, 'b'>,
+                animate_any::<S, 'b'>,
+            ),
+            _ => panic!("Part must be 'a' or 'b'"),
+        };
+        Solver {
+            day,
+            part,
+            description,
+            dir,
+            parse: parse_any::<S>,
+            solve,
+            render,
+            animate,
+        }
+    }
+
+    // Read the input from the source and solve it, locating any parse error in the input.
+    pub fn solve_input(&self, source: &InputSource) -> Result<Answer, Error> {
+        let input = self.read_input(source)?;
+        let parsed = self.parse_input(&input, source)?;
+        self.solve_parsed(parsed.as_ref())
+    }
+
+    // Solve the parsed input, reporting an arithmetic overflow as an error. Any other panic is
+    // passed on.
+    pub fn solve_parsed(&self, parsed: &dyn Any) -> Result<Answer, Error> {
+        quiet_overflows();
+        let solving = SOLVING.replace(true);
+        let result = panic::catch_unwind(AssertUnwindSafe(|| (self.solve)(parsed)));
+        SOLVING.set(solving);
+        result.or_else(|payload| match overflow_message(payload.as_ref()) {
+            Some(message) => Err(Error::Overflow(message.to_string())),
+            None => panic::resume_unwind(payload),
+        })
+    }
+
+    // Read the input and normalize it, or in strict mode check that it does not need to be.
+    pub fn read_input(&self, source: &InputSource) -> Result<String, Error> {
+        let dir = Path::new(self.dir);
+        let input = source.read(dir).map_err(Error::Input)?;
+        if !is_strict() {
+            return Ok(normalize(&input));
+        }
+        match check_regular(&input) {
+            Ok(()) => Ok(input),
+            Err(error) => Err(Error::Parse(error.locate(&input, source.path(dir)))),
+        }
+    }
+
+    // Solve every input in the answers manifest of the day, and describe each answer that is not
+    // the expected one.
+    pub fn check_answers(&self) -> Vec<String> {
+        let name = format!("Day {} part {}", self.day, self.part);
+        let dir = Path::new(self.dir);
+        let expected_answers = match read_answers(dir, self.part) {
+            Ok(expected_answers) => expected_answers,
+            Err(message) => return vec![format!("{name}: {message}")],
+        };
+        let mut failures = Vec::new();
+        if !expected_answers
+            .iter()
+            .any(|expected| expected.input == PUZZLE_INPUT)
+        {
+            failures.push(format!("{name}: no answer for {PUZZLE_INPUT}"));
+        }
+        for expected in expected_answers
+            .into_iter()
+            .filter(ExpectedAnswer::is_known)
+        {
+            let source = InputSource::Path(dir.join(&expected.input));
+            match self.solve_input(&source) {
+                Ok(answer) if answer.to_string() == expected.answer => (),
+                Ok(answer) => failures.push(format!(
+                    "{name}, {}: answer {answer}, expected {}",
+                    expected.input, expected.answer
+                )),
+                Err(error) => failures.push(format!("{name}, {}: {error}", expected.input)),
+            }
+        }
+        failures
+    }
+
+    // The expected answer for the puzzle input in the answers manifest of the day, if it has one.
+    pub fn expected_puzzle_answer(&self) -> Result<Option<String>, String> {
+        self.expected_answer(&InputSource::Puzzle)
+    }
+
+    // The expected answer for an input in the answers manifest of the day, if it has one. Only the
+    // inputs in the directory of the day crate can be in the manifest.
+    pub fn expected_answer(&self, source: &InputSource) -> Result<Option<String>, String> {
+        let dir = Path::new(self.dir);
+        let Some(path) = source.path(dir) else {
+            return Ok(None);
+        };
+        if path.parent() != Some(dir) {
+            return Ok(None);
+        }
+        let expected_answers = read_answers(dir, self.part)?;
+        Ok(expected_answers
+            .into_iter()
+            .find(|expected| path.file_name() == Some(expected.input.as_ref()))
+            .filter(ExpectedAnswer::is_known)
+            .map(|expected| expected.answer))
+    }
+
+    // Parse the input, locating any parse error in the input.
+    pub fn parse_input(&self, input: &str, source: &InputSource) -> Result<Box<dyn Any>, Error> {
+        let file = source.path(Path::new(self.dir));
+        (self.parse)(input).map_err(|error| Error::Parse(error.locate(input, file)))
+    }
+}
+
+fn parse_any<S: Solution>(input: &str) -> Result<Box<dyn Any>, ParseError> {
+    S::parse(input).map(|parsed| Box::new(parsed) as Box<dyn Any>)
+}
+
+fn downcast<S: Solution>(parsed: &dyn Any) -> &S::Input {
+    parsed
+        .downcast_ref()
+        .expect("Parsed input has the wrong type")
+}
+
+fn solve_part_a<S: Solution>(parsed: &dyn Any) -> Answer {
+    S::part_a(downcast::<S>(parsed)).expect("Part a is not solved")
+}
+
+fn solve_part_b<S: Solution>(parsed: &dyn Any) -> Answer {
+    S::part_b(downcast::<S>(parsed)).expect("Part b is not solved")
+}
+
+fn render_any<S: Solution, const PART: char>(parsed: &dyn Any) -> Option<Picture> {
+    S::render(downcast::<S>(parsed), PART)
+}
+
+fn animate_any<S: Solution, const PART: char>(
+    parsed: &dyn Any,
+    animation: &mut Animation,
+) -> Option<Answer> {
+    S::animate(downcast::<S>(parsed), PART, animation)
+}
+
+// Used by the binary of each day crate: solve each part of the day for the input given on the
+// command line, which defaults to the puzzle input of the day.
+pub fn run(solvers: &[Solver]) {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let source = InputSource::from_args(&args).unwrap_or_else(|err| {
+        eprintln!("{err}");
+        eprintln!("Usage: day_{} [PATH | - | --example [N]]", solvers[0].day);
+        exit(2);
+    });
+    if let Err(err) = crate::trace::configure_from_env() {
+        eprintln!("{err}");
+        exit(2);
+    }
+    for solver in solvers {
+        match solver.solve_input(&source) {
+            Ok(answer) => println!("{}: {answer}", solver.description),
+            Err(error) => {
+                eprint!("{error}");
+                exit(1);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_solver_from_solution() {
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<i64>;
+
+        fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+            input.split_whitespace().map(crate::parse_number).collect()
+        }
+
+        fn part_b(numbers: &Vec<i64>) -> Option<Answer> {
+            Some(numbers.iter().sum::<i64>().into())
+        }
+    }
+
+    let solver = Solver::new::<Sum>(1, 'b', "Sum", "");
+    let parsed = (solver.parse)("1 2 3").unwrap();
+    assert_eq!((solver.solve)(parsed.as_ref()).to_string(), "6");
+    assert!((solver.parse)("1 x").is_err());
+}
+
+#[test]
+fn test_overflow_is_an_error() {
+    struct Product;
+
+    impl Solution for Product {
+        type Input = Vec<u8>;
+
+        fn parse(input: &str) -> Result<Vec<u8>, ParseError> {
+            input.split_whitespace().map(crate::parse_number).collect()
+        }
+
+        // Overflows even without overflow checks, like an unchecked solution would in a checked
+        // build
+        fn part_a(numbers: &Vec<u8>) -> Option<Answer> {
+            let product = numbers
+                .iter()
+                .try_fold(1u8, |product, &n| product.checked_mul(n));
+            Some(product.expect("attempt to multiply with overflow").into())
+        }
+    }
+
+    let solver = Solver::new::<Product>(1, 'a', "Product", "");
+    let solve = |input| solver.solve_parsed((solver.parse)(input).unwrap().as_ref());
+    assert_eq!(solve("3 5").unwrap().to_string(), "15");
+    let error = solve("16 16").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "error: arithmetic overflow: attempt to multiply with overflow\n"
+    );
+}
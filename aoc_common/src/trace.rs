@@ -0,0 +1,207 @@
+// Debug tracing: the days emit structured events, e.g. a pulse sent from one module to another, to a
+// target such as day20::pulse. A filter like "day20::pulse,day16=debug" says which targets are
+// traced and at what level, and the events that pass it are written to stderr or a file, one line
+// per event with its fields as key=value pairs. Tracing is off until it is configured, and then
+// costs a single atomic load per event that is not traced.
+
+use std::fmt::Display;
+use std::fs::File;
+use std::io::{LineWriter, Write, stderr};
+use std::path::Path;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+// The environment variables with the filter and the file to write the events to, used when the
+// filter is not given on the command line.
+pub const TRACE_ENV: &str = "AOC_TRACE";
+pub const TRACE_FILE_ENV: &str = "AOC_TRACE_FILE";
+
+// From the least to the most detailed
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Level {
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    fn name(self) -> &'static str {
+        match self {
+            Level::Info => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        }
+    }
+}
+
+// The most detailed level traced for each target prefix, where None turns a target off.
+#[derive(Debug, Default, PartialEq)]
+pub struct Filter {
+    directives: Vec<(String, Option<Level>)>,
+}
+
+impl Filter {
+    // Comma separated targets, each optionally with =level (info, debug, trace or off). A target
+    // without a level is traced at every level. A target also covers the targets below it, so day20
+    // covers day20::pulse.
+    pub fn parse(spec: &str) -> Result<Filter, String> {
+        let mut directives = Vec::new();
+        for directive in spec.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+            let (target, level) = match directive.split_once('=') {
+                Some((target, level)) => (target.trim(), level.trim()),
+                None => (directive, "trace"),
+            };
+            let level = match level {
+                "info" => Some(Level::Info),
+                "debug" => Some(Level::Debug),
+                "trace" => Some(Level::Trace),
+                "off" => None,
+                _ => return Err(format!("Unknown trace level {level} for {target}")),
+            };
+            directives.push((target.to_string(), level));
+        }
+        Ok(Filter { directives })
+    }
+
+    // The most specific target prefix decides.
+    pub fn enabled(&self, target: &str, level: Level) -> bool {
+        let covers = |prefix: &str| {
+            target
+                .strip_prefix(prefix)
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+        };
+        self.directives
+            .iter()
+            .filter(|(prefix, _)| covers(prefix))
+            .max_by_key(|(prefix, _)| prefix.len())
+            .and_then(|&(_, max_level)| max_level)
+            .is_some_and(|max_level| level <= max_level)
+    }
+}
+
+struct Tracer {
+    filter: Filter,
+    output: Box<dyn Write + Send>,
+}
+
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+static TRACER: Mutex<Option<Tracer>> = Mutex::new(None);
+
+// Trace the events that pass the filter to the file, or to stderr. An empty filter turns tracing off.
+pub fn configure(spec: &str, file: Option<&Path>) -> Result<(), String> {
+    let filter = Filter::parse(spec)?;
+    let output: Box<dyn Write + Send> = match file {
+        Some(path) => Box::new(LineWriter::new(File::create(path).map_err(|err| {
+            format!("Cannot create trace file {}: {err}", path.display())
+        })?)),
+        None => Box::new(stderr()),
+    };
+    let active = !filter.directives.is_empty();
+    *TRACER.lock().unwrap() = Some(Tracer { filter, output });
+    ACTIVE.store(active, Ordering::Relaxed);
+    Ok(())
+}
+
+// Configure tracing from the environment variables, if the filter is set.
+pub fn configure_from_env() -> Result<(), String> {
+    match std::env::var(TRACE_ENV) {
+        Ok(spec) => {
+            let file = std::env::var_os(TRACE_FILE_ENV);
+            configure(&spec, file.as_deref().map(Path::new))
+        }
+        Err(_) => Ok(()),
+    }
+}
+
+// Inlined, so that the days pay only for the load while tracing is off
+#[inline]
+pub fn enabled(target: &str, level: Level) -> bool {
+    ACTIVE.load(Ordering::Relaxed) && filter_enabled(target, level)
+}
+
+fn filter_enabled(target: &str, level: Level) -> bool {
+    TRACER
+        .lock()
+        .unwrap()
+        .as_ref()
+        .is_some_and(|tracer| tracer.filter.enabled(target, level))
+}
+
+// Write an event, without checking the filter: use the trace_event macro instead.
+pub fn event(target: &str, level: Level, name: &str, fields: &[(&str, &dyn Display)]) {
+    if let Some(tracer) = TRACER.lock().unwrap().as_mut() {
+        // A trace that can't be written is not worth failing the solution for
+        let _ = writeln!(
+            tracer.output,
+            "{}",
+            format_event(target, level, name, fields)
+        );
+    }
+}
+
+// The line of an event, e.g. "day20::pulse debug pulse_sent src=a value=low dest=b". Values that
+// are empty or contain spaces, quotes or = are quoted.
+fn format_event(target: &str, level: Level, name: &str, fields: &[(&str, &dyn Display)]) -> String {
+    let mut line = format!("{target} {} {name}", level.name());
+    for (key, value) in fields {
+        let value = value.to_string();
+        if value.is_empty() || value.contains([' ', '"', '=']) {
+            line += &format!(" {key}={value:?}");
+        } else {
+            line += &format!(" {key}={value}");
+        }
+    }
+    line
+}
+
+// Emit an event if its target is traced at its level, e.g.
+// trace_event!(Debug, "day20::pulse", "pulse_sent", src = name, dest = dest_name);
+// The fields are only evaluated when the event is traced.
+#[macro_export]
+macro_rules! trace_event {
+    ($level:ident, $target:expr, $name:expr $(, $key:ident = $value:expr)* $(,)?) => {
+        if $crate::trace::enabled($target, $crate::trace::Level::$level) {
+            $crate::trace::event(
+                $target,
+                $crate::trace::Level::$level,
+                $name,
+                &[$((stringify!($key), &$value as &dyn ::std::fmt::Display)),*],
+            );
+        }
+    };
+}
+
+#[test]
+fn test_filter() {
+    let filter = Filter::parse("day20::pulse, day16=debug,day16::beam=off,day19=info").unwrap();
+    assert!(filter.enabled("day20::pulse", Level::Trace));
+    assert!(!filter.enabled("day20", Level::Info));
+    assert!(!filter.enabled("day20::pulses", Level::Info));
+    assert!(filter.enabled("day16::split", Level::Debug));
+    assert!(!filter.enabled("day16::split", Level::Trace));
+    assert!(!filter.enabled("day16::beam", Level::Info));
+    assert!(filter.enabled("day19::partition", Level::Info));
+    assert!(!filter.enabled("day19::partition", Level::Debug));
+    assert_eq!(Filter::parse(""), Ok(Filter::default()));
+    assert!(Filter::parse("day20=loud").is_err());
+}
+
+#[test]
+fn test_format_event() {
+    let line = format_event(
+        "day20::pulse",
+        Level::Debug,
+        "pulse_sent",
+        &[
+            ("src", &"broadcaster"),
+            ("count", &3),
+            ("rule", &"a<2006:qkq"),
+        ],
+    );
+    assert_eq!(
+        line,
+        "day20::pulse debug pulse_sent src=broadcaster count=3 rule=a<2006:qkq"
+    );
+    let line = format_event("day19", Level::Info, "x", &[("src", &""), ("s", &"a=b c")]);
+    assert_eq!(line, r#"day19 info x src="" s="a=b c""#);
+}
@@ -0,0 +1,107 @@
+use aoc_common::{Answer, ParseError, Solution, Solver, solver};
+
+pub mod part_a;
+pub mod part_b;
+
+pub const SOLVER_A: Solver = solver! {
+    day: 1,
+    part: 'a',
+    description: "Sum of calibration values",
+    solution: Day1,
+};
+
+pub const SOLVER_B: Solver = solver! {
+    day: 1,
+    part: 'b',
+    description: "Sum of calibration values",
+    solution: Day1,
+};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<Calibration>;
+
+    fn parse(input: &str) -> Result<Vec<Calibration>, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(input: &Vec<Calibration>) -> Option<Answer> {
+        Some(part_a::solve(input).into())
+    }
+
+    fn part_b(input: &Vec<Calibration>) -> Option<Answer> {
+        Some(part_b::solve(input).into())
+    }
+}
+
+// The first and last digit of a line. Part b also counts the digits that are spelled out, so a line
+// may only have those.
+pub struct Calibration {
+    digits: Option<(u32, u32)>,
+    digits_or_words: (u32, u32),
+}
+
+pub fn parse(input: &str) -> Result<Vec<Calibration>, ParseError> {
+    input.lines().map(parse_line).collect()
+}
+
+fn parse_line(line: &str) -> Result<Calibration, ParseError> {
+    let digits_or_words = first_and_last_digit(line, &DIGIT_WORDS)
+        .ok_or_else(|| ParseError::missing("digit", line))?;
+    Ok(Calibration {
+        digits: first_and_last_digit(line, &DIGIT_WORDS[..10]),
+        digits_or_words,
+    })
+}
+
+// The digits, followed by the digits spelled out
+const DIGIT_WORDS: [(&str, u32); 20] = [
+    ("0", 0),
+    ("1", 1),
+    ("2", 2),
+    ("3", 3),
+    ("4", 4),
+    ("5", 5),
+    ("6", 6),
+    ("7", 7),
+    ("8", 8),
+    ("9", 9),
+    ("zero", 0),
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+
+enum Direction {
+    FromStart,
+    FromEnd,
+}
+
+fn first_and_last_digit(line: &str, digit_words: &[(&str, u32)]) -> Option<(u32, u32)> {
+    let first_digit = find_digit(line, Direction::FromStart, digit_words)?;
+    let last_digit = find_digit(line, Direction::FromEnd, digit_words)?;
+    Some((first_digit, last_digit))
+}
+
+fn find_digit(line: &str, direction: Direction, digit_words: &[(&str, u32)]) -> Option<u32> {
+    let range: Vec<usize> = match direction {
+        Direction::FromStart => line.char_indices().map(|(pos, _)| pos).collect(),
+        Direction::FromEnd => line.char_indices().map(|(pos, _)| pos).rev().collect(),
+    };
+    for pos in range {
+        let line_slice = &line[pos..];
+        for (digit_str, digit_value) in digit_words.iter() {
+            if line_slice.starts_with(digit_str) {
+                return Some(*digit_value);
+            }
+        }
+    }
+    None
+}
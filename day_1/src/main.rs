@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::run(&[day_1::SOLVER_A, day_1::SOLVER_B]);
+}
@@ -0,0 +1,16 @@
+use crate::Calibration;
+use aoc_common::assume;
+
+pub fn solve(calibrations: &[Calibration]) -> u64 {
+    let mut sum: u64 = 0;
+    for calibration in calibrations {
+        assume(
+            calibration.digits.is_some(),
+            "every line has a digit that is not spelled out",
+        );
+        let (first_digit, last_digit) = calibration.digits.unwrap();
+        let calibration_value = first_digit * 10 + last_digit;
+        sum += u64::from(calibration_value);
+    }
+    sum
+}
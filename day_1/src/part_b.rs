@@ -0,0 +1,11 @@
+use crate::Calibration;
+
+pub fn solve(calibrations: &[Calibration]) -> u64 {
+    let mut sum: u64 = 0;
+    for calibration in calibrations {
+        let (first_digit, last_digit) = calibration.digits_or_words;
+        let calibration_value = first_digit * 10 + last_digit;
+        sum += u64::from(calibration_value);
+    }
+    sum
+}
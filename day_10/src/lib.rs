@@ -0,0 +1,122 @@
+use aoc_common::{Answer, Dir, Grid, ParseError, Picture, Pos, Solution, Solver, assume, solver};
+
+pub mod part_a;
+pub mod part_b;
+
+pub const SOLVER_A: Solver = solver! {
+    day: 10,
+    part: 'a',
+    description: "Maximum distance in pipe from animal",
+    solution: Day10,
+};
+
+pub const SOLVER_B: Solver = solver! {
+    day: 10,
+    part: 'b',
+    description: "Count of inside positions",
+    solution: Day10,
+};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Map, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(input: &Map) -> Option<Answer> {
+        Some(part_a::solve(input).into())
+    }
+
+    fn part_b(input: &Map) -> Option<Answer> {
+        Some(part_b::solve(input).into())
+    }
+
+    fn render(input: &Map, part: char) -> Option<Picture> {
+        (part == 'b').then(|| part_b::render(input))
+    }
+}
+
+pub type Map = Grid<char>;
+
+// The animal is on the one S of the map.
+pub fn parse(input: &str) -> Result<Map, ParseError> {
+    let map = Grid::try_parse(input, "tile", |c| "|-LJ7F.S".contains(c).then_some(c))?;
+    let mut animals = input.match_indices('S');
+    if animals.next().is_none() {
+        return Err(ParseError::missing("animal 'S'", &input[..0]));
+    }
+    if let Some((index, animal)) = animals.next() {
+        return Err(ParseError::expected(
+            "only one S",
+            &input[index..index + animal.len()],
+        ));
+    }
+    Ok(map)
+}
+
+fn locate_animal(map: &Map) -> Pos {
+    map.position(|&c| c == 'S').unwrap().into()
+}
+
+fn locate_pipe_starts_from_animal(map: &Map, animal_pos: Pos) -> (Pos, Pos) {
+    // This implementation assumes that there are exactly two positions around the animal
+    // with pipes that connect to the animal. In general, this is not true, but it is true
+    // for the provided inputs. If it was not true, it would make the algorithm more complex.
+    let mut pipe_starts = Vec::new();
+    for dir in Dir::ORTHOGONAL {
+        let pos = animal_pos + dir;
+        if map.get(pos.x, pos.y).is_none_or(|&c| c == '.') {
+            continue;
+        }
+        if pipe_neighbors(map, pos).contains(&animal_pos) {
+            pipe_starts.push(pos);
+        }
+    }
+    assume(
+        pipe_starts.len() == 2,
+        "exactly two pipes connect to the animal",
+    );
+    let a = pipe_starts[0];
+    let b = pipe_starts[1];
+    (a, b)
+}
+
+fn follow_pipe(map: &Map, enter_pos: Pos, pipe_pos: Pos) -> Pos {
+    let neighbors = pipe_neighbors(map, pipe_pos);
+    assume(neighbors.len() == 2, "the loop stays on the map");
+    assume(
+        neighbors.contains(&enter_pos),
+        "each pipe of the loop connects to the one before",
+    );
+    if enter_pos == neighbors[0] {
+        neighbors[1]
+    } else {
+        neighbors[0]
+    }
+}
+
+// The two directions in which a pipe connects.
+fn pipe_connections(c: char) -> Option<(Dir, Dir)> {
+    match c {
+        '|' => Some((Dir::N, Dir::S)),
+        '-' => Some((Dir::W, Dir::E)),
+        'L' => Some((Dir::N, Dir::E)),
+        'J' => Some((Dir::N, Dir::W)),
+        '7' => Some((Dir::W, Dir::S)),
+        'F' => Some((Dir::E, Dir::S)),
+        _ => None,
+    }
+}
+
+fn pipe_neighbors(map: &Map, pipe_pos: Pos) -> Vec<Pos> {
+    let connections = pipe_connections(map[pipe_pos]);
+    assume(connections.is_some(), "the loop only goes through pipes");
+    let (dir_a, dir_b) = connections.unwrap();
+    [pipe_pos + dir_a, pipe_pos + dir_b]
+        .into_iter()
+        .filter(|pos| map.contains(pos.x, pos.y))
+        .collect()
+}
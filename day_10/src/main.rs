@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::run(&[day_10::SOLVER_A, day_10::SOLVER_B]);
+}
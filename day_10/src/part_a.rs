@@ -0,0 +1,31 @@
+use crate::{Map, follow_pipe, locate_animal, locate_pipe_starts_from_animal};
+use aoc_common::Pos;
+
+pub fn solve(map: &Map) -> usize {
+    let animal_pos = locate_animal(map);
+    let (pipe_pos_a, pipe_pos_b) = locate_pipe_starts_from_animal(map, animal_pos);
+    follow_two_pipes_until_they_meet(map, animal_pos, pipe_pos_a, pipe_pos_b)
+}
+
+fn follow_two_pipes_until_they_meet(
+    map: &Map,
+    start_pos: Pos,
+    pipe_pos_a: Pos,
+    pipe_pos_b: Pos,
+) -> usize {
+    let mut pipe_pos_a = pipe_pos_a;
+    let mut pipe_pos_b = pipe_pos_b;
+    let mut start_pos_a = start_pos;
+    let mut start_pos_b = start_pos;
+    let mut distance = 0;
+    loop {
+        let end_pos_a = follow_pipe(map, start_pos_a, pipe_pos_a);
+        (start_pos_a, pipe_pos_a) = (pipe_pos_a, end_pos_a);
+        let end_pos_b = follow_pipe(map, start_pos_b, pipe_pos_b);
+        (start_pos_b, pipe_pos_b) = (pipe_pos_b, end_pos_b);
+        distance += 1;
+        if start_pos_a == start_pos_b {
+            return distance;
+        }
+    }
+}
@@ -0,0 +1,148 @@
+use crate::{Map, follow_pipe, locate_animal, locate_pipe_starts_from_animal, pipe_connections};
+use aoc_common::{Dir, Grid, Paint, Picture, Pos, Rgb};
+
+// Different types of maps:
+// Symbol map : the original map read from the input file with symbols such as | - L J 7 F
+// Pipe map: a map that only contains the symbols for the pipes (same resolution, same symbols)
+// Hires map: a map that has true or false for dots present, at triple the resolution of the symbol map
+
+pub fn solve(symbol_map: &Map) -> usize {
+    let hires_map = make_flooded_hires_map(symbol_map);
+    let inside_map = make_inside_map(&hires_map);
+    count_inside(&inside_map)
+}
+
+// The pipe loop at the triple resolution of the hires map, with the tiles outside the loop
+pub fn render(symbol_map: &Map) -> Picture {
+    let hires_map = make_flooded_hires_map(symbol_map);
+    let cells = hires_map.map(|&c| match c {
+        'X' => Paint::Kind("loop"),
+        'o' => Paint::Kind("outside"),
+        _ => Paint::Kind("inside"),
+    });
+    Picture {
+        cells,
+        palette: vec![
+            ("loop", Rgb(0x30, 0x30, 0x30)),
+            ("outside", Rgb(0xd0, 0xe8, 0xff)),
+            ("inside", Rgb(0xff, 0xb0, 0x20)),
+        ],
+    }
+}
+
+fn make_flooded_hires_map(symbol_map: &Map) -> Map {
+    let mut symbol_map = symbol_map.clone();
+    let animal_pos = locate_animal(&symbol_map);
+    let (pipe_start_pos, pipe_end_pos) = locate_pipe_starts_from_animal(&symbol_map, animal_pos);
+    replace_animal_with_pipe(&mut symbol_map, animal_pos, pipe_start_pos, pipe_end_pos);
+    let pipe_map = make_pipe_map(&symbol_map, animal_pos, pipe_start_pos, pipe_end_pos);
+    let mut hires_map = make_hires_map(&pipe_map);
+    flood_outside(&mut hires_map);
+    hires_map
+}
+
+const PIPES: [char; 6] = ['|', '-', 'L', 'J', '7', 'F'];
+
+fn replace_animal_with_pipe(
+    symbol_map: &mut Map,
+    animal_pos: Pos,
+    pipe_start_pos: Pos,
+    pipe_end_pos: Pos,
+) {
+    let dir_to = |pos: Pos| {
+        Dir::ORTHOGONAL
+            .into_iter()
+            .find(|&dir| animal_pos + dir == pos)
+    };
+    let dirs = [dir_to(pipe_start_pos), dir_to(pipe_end_pos)];
+    let pipe_under_animal_char = PIPES
+        .into_iter()
+        .find(|&c| {
+            let (dir_a, dir_b) = pipe_connections(c).unwrap();
+            dirs == [Some(dir_a), Some(dir_b)] || dirs == [Some(dir_b), Some(dir_a)]
+        })
+        .expect("Could not determine pipe at animal position");
+    symbol_map[animal_pos] = pipe_under_animal_char;
+}
+
+fn make_pipe_map(symbol_map: &Map, start_pos: Pos, pipe_start_pos: Pos, pipe_end_pos: Pos) -> Map {
+    let mut pipe_map = Grid::new(symbol_map.width(), symbol_map.height(), '.');
+    let mut pipe_pos = pipe_start_pos;
+    let mut start_pos = start_pos;
+    loop {
+        pipe_map[pipe_pos] = symbol_map[pipe_pos];
+        let next_pos = follow_pipe(symbol_map, start_pos, pipe_pos);
+        (start_pos, pipe_pos) = (pipe_pos, next_pos);
+        if start_pos == pipe_end_pos {
+            pipe_map[pipe_pos] = symbol_map[pipe_pos];
+            break;
+        }
+    }
+    pipe_map
+}
+
+fn make_hires_map(pipe_map: &Map) -> Map {
+    // Create a hires map with the three times the dimensions as the pipe map
+    let mut hires_map = Grid::new(pipe_map.width() * 3, pipe_map.height() * 3, '.');
+    for (x, y) in pipe_map.positions() {
+        let c = pipe_map[(x, y)];
+        let hires_box = match c {
+            '|' => [['.', 'X', '.'], ['.', 'X', '.'], ['.', 'X', '.']],
+            '-' => [['.', '.', '.'], ['X', 'X', 'X'], ['.', '.', '.']],
+            'L' => [['.', 'X', '.'], ['.', 'X', 'X'], ['.', '.', '.']],
+            'J' => [['.', 'X', '.'], ['X', 'X', '.'], ['.', '.', '.']],
+            '7' => [['.', '.', '.'], ['X', 'X', '.'], ['.', 'X', '.']],
+            'F' => [['.', '.', '.'], ['.', 'X', 'X'], ['.', 'X', '.']],
+            '.' => [['.', '.', '.'], ['.', '.', '.'], ['.', '.', '.']],
+            _ => panic!("Unexpected pipe character {c}"),
+        };
+        for (dy, hires_box_row) in hires_box.iter().enumerate() {
+            for (dx, &hires_c) in hires_box_row.iter().enumerate() {
+                hires_map[(x * 3 + dx, y * 3 + dy)] = hires_c;
+            }
+        }
+    }
+    hires_map
+}
+
+fn flood_outside(map: &mut Map) {
+    let mut flood_front = Vec::new();
+    flood_front.push(Pos::new(0, 0));
+    while let Some(pos) = flood_front.pop() {
+        let Some(c) = map.get_mut(pos.x, pos.y) else {
+            continue;
+        };
+        if *c == 'X' || *c == 'o' {
+            continue;
+        }
+        *c = 'o';
+        for dir in Dir::ORTHOGONAL {
+            flood_front.push(pos + dir);
+        }
+    }
+}
+
+fn make_inside_map(hires_map: &Map) -> Map {
+    assert!(hires_map.width().is_multiple_of(3) && hires_map.height().is_multiple_of(3));
+    let mut inside_map = Grid::new(hires_map.width() / 3, hires_map.height() / 3, '.');
+    for (inside_x, inside_y) in inside_map.positions() {
+        let hires_base_x = inside_x * 3;
+        let hires_base_y = inside_y * 3;
+        let mut all_inside = true;
+        for dy in 0..3 {
+            for dx in 0..3 {
+                if hires_map[(hires_base_x + dx, hires_base_y + dy)] != '.' {
+                    all_inside = false;
+                }
+            }
+        }
+        if all_inside {
+            inside_map[(inside_x, inside_y)] = 'I';
+        }
+    }
+    inside_map
+}
+
+fn count_inside(inside_map: &Map) -> usize {
+    inside_map.cells().filter(|&&c| c == 'I').count()
+}
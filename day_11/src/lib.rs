@@ -1,23 +1,56 @@
-use std::fs::read_to_string;
+use aoc_common::{Answer, ParseError, Solution, Solver, solver};
 
-struct Universe {
+pub mod part_a;
+pub mod part_b;
+
+pub const SOLVER_A: Solver = solver! {
+    day: 11,
+    part: 'a',
+    description: "Total distance",
+    solution: Day11,
+};
+
+pub const SOLVER_B: Solver = solver! {
+    day: 11,
+    part: 'b',
+    description: "Total distance",
+    solution: Day11,
+};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Universe;
+
+    fn parse(input: &str) -> Result<Universe, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(input: &Universe) -> Option<Answer> {
+        Some(part_a::solve(input).into())
+    }
+
+    fn part_b(input: &Universe) -> Option<Answer> {
+        Some(part_b::solve(input).into())
+    }
+}
+
+#[derive(Clone)]
+pub struct Universe {
     max_x: usize,
     max_y: usize,
     galaxies: Vec<(usize, usize)>,
 }
 
-fn main() {
-    let mut universe = read_universe();
-    expand_universe(&mut universe);
-    let total = sum_distances(&universe);
-    println!("Total distance: {}", total);
+pub fn parse(input: &str) -> Result<Universe, ParseError> {
+    Ok(read_universe(input))
 }
 
-fn read_universe() -> Universe {
+fn read_universe(input: &str) -> Universe {
     let mut galaxies = Vec::new();
     let mut y = 0;
     let mut max_x = 0;
-    for line in read_to_string("puzzle_input").unwrap().lines() {
+    for line in input.lines() {
         for (x, c) in line.chars().enumerate() {
             if c == '#' {
                 galaxies.push((x, y));
@@ -33,10 +66,10 @@ fn read_universe() -> Universe {
     }
 }
 
-fn expand_universe(universe: &mut Universe) {
+// Grow each empty column and row by the given number of columns or rows
+fn expand_universe(universe: &mut Universe, grow: usize) {
     let ec = empty_columns(universe);
     let er = empty_rows(universe);
-    let grow = 999_999;
     for x in ec.iter().rev() {
         for galaxy in universe.galaxies.iter_mut() {
             if galaxy.0 >= *x {
@@ -96,8 +129,8 @@ fn sum_distances(universe: &Universe) -> usize {
         for j in (i + 1)..nr_galaxies {
             let (x1, y1) = universe.galaxies[i];
             let (x2, y2) = universe.galaxies[j];
-            total += (x1 as isize - x2 as isize).abs() as usize;
-            total += (y1 as isize - y2 as isize).abs() as usize;
+            total += (x1 as isize - x2 as isize).unsigned_abs();
+            total += (y1 as isize - y2 as isize).unsigned_abs();
         }
     }
     total
@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::run(&[day_11::SOLVER_A, day_11::SOLVER_B]);
+}
@@ -0,0 +1,7 @@
+use crate::{Universe, expand_universe, sum_distances};
+
+pub fn solve(universe: &Universe) -> usize {
+    let mut universe = universe.clone();
+    expand_universe(&mut universe, 1);
+    sum_distances(&universe)
+}
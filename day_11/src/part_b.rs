@@ -0,0 +1,8 @@
+use crate::{Universe, expand_universe, sum_distances};
+
+pub fn solve(universe: &Universe) -> usize {
+    let mut universe = universe.clone();
+    // Each empty column and row becomes a million of them
+    expand_universe(&mut universe, 999_999);
+    sum_distances(&universe)
+}
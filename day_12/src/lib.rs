@@ -0,0 +1,60 @@
+use aoc_common::{
+    Answer, ParseError, Solution, Solver, only_chars, parse_number, solver, split_once,
+};
+
+pub mod part_a;
+pub mod part_b;
+
+pub const SOLVER_A: Solver = solver! {
+    day: 12,
+    part: 'a',
+    description: "Total number of possible arrangements",
+    solution: Day12,
+};
+
+pub const SOLVER_B: Solver = solver! {
+    day: 12,
+    part: 'b',
+    description: "Total number of possible arrangements",
+    solution: Day12,
+};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<Record>;
+
+    fn parse(input: &str) -> Result<Vec<Record>, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(input: &Vec<Record>) -> Option<Answer> {
+        Some(part_a::solve(input).into())
+    }
+
+    fn part_b(input: &Vec<Record>) -> Option<Answer> {
+        Some(part_b::solve(input).into())
+    }
+}
+
+// The symbols of a record and the counts of its groups of #'s
+pub type Record = (String, Vec<u32>);
+
+pub fn parse(input: &str) -> Result<Vec<Record>, ParseError> {
+    input.lines().map(parse_record).collect()
+}
+
+fn parse_record(line: &str) -> Result<Record, ParseError> {
+    let (symbols, counts) = split_once(line, " ")?;
+    only_chars(symbols, "?#.", "spring")?;
+    let mut group_counts = Vec::new();
+    for count in counts.split(',') {
+        let group_count: u32 = parse_number(count)?;
+        // A group has at least one #
+        if group_count == 0 {
+            return Err(ParseError::invalid_number(count));
+        }
+        group_counts.push(group_count);
+    }
+    Ok((symbols.to_string(), group_counts))
+}
@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::run(&[day_12::SOLVER_A, day_12::SOLVER_B]);
+}
@@ -1,29 +1,22 @@
-use std::fs::read_to_string;
+use crate::Record;
 
-fn main() {
+pub fn solve(records: &[Record]) -> u32 {
     let mut total = 0;
-    for record in read_to_string("puzzle_input").unwrap().lines() {
+    for record in records {
         total += nr_possible_arrangements(record);
     }
-    println!("Total number of possible arrangements: {}", total);
+    total
 }
 
-fn nr_possible_arrangements(record: &str) -> u32 {
-    let (symbols, counts) = record.split_once(' ').unwrap();
-    let symbols = String::from(symbols);
-    let counts: Vec<u32> = counts
-        .split(',')
-        .map(|s| s.parse::<u32>().unwrap())
-        .collect();
-    let nr_arrangements = count_arrangements(&symbols, &counts);
-    nr_arrangements
+pub fn nr_possible_arrangements((symbols, counts): &Record) -> u32 {
+    count_arrangements(symbols, counts)
 }
 
-fn count_arrangements(symbols: &String, counts: &[u32]) -> u32 {
+fn count_arrangements(symbols: &str, counts: &[u32]) -> u32 {
     let pos = symbols.find('?');
     match pos {
         Some(pos) => {
-            let mut symbols = symbols.clone();
+            let mut symbols = symbols.to_string();
             symbols.replace_range(pos..=pos, "#");
             let hash_arrangements = count_arrangements(&symbols, counts);
             symbols.replace_range(pos..=pos, ".");
@@ -52,15 +45,13 @@ fn symbols_match_counts(symbols: &str, counts: &[u32]) -> bool {
                 in_group = true;
                 group_count = 1;
             }
-        } else if c == '.' {
-            if in_group {
-                actual_counts.push(group_count);
-                in_group = false;
-            }
+        } else if c == '.' && in_group {
+            actual_counts.push(group_count);
+            in_group = false;
         }
     }
     if in_group {
         actual_counts.push(group_count);
     }
-    return actual_counts == counts;
+    actual_counts == counts
 }
@@ -1,27 +1,24 @@
+use crate::Record;
+use aoc_common::metrics;
 use memoize::memoize;
-use std::fs::read_to_string;
 
-fn main() {
+pub fn solve(records: &[Record]) -> u64 {
+    // Start from an empty cache, so that solving again (e.g. when benchmarking) does the same work
+    memoized_flush_cached_nr_arrangements();
     let mut total = 0;
-    for record in read_to_string("puzzle_input").unwrap().lines() {
+    for record in records {
         total += nr_arrangements_for_record(record);
     }
-    println!("Total number of possible arrangements: {}", total);
+    total
 }
 
-fn nr_arrangements_for_record(record: &str) -> u64 {
-    let (short_symbols, counts) = record.split_once(' ').unwrap();
-    let short_symbols = String::from(short_symbols);
+pub fn nr_arrangements_for_record((short_symbols, short_counts): &Record) -> u64 {
     // Quintuple the symbols as requested in the puzzle.
     // Add a trailing . to ensure the last group of #'s is terminated.
     let symbols = format!(
         "{}?{}?{}?{}?{}.",
         short_symbols, short_symbols, short_symbols, short_symbols, short_symbols
     );
-    let short_counts: Vec<u32> = counts
-        .split(',')
-        .map(|s| s.parse::<u32>().unwrap())
-        .collect();
     let counts = [
         &short_counts[..],
         &short_counts[..],
@@ -30,12 +27,33 @@ fn nr_arrangements_for_record(record: &str) -> u64 {
         &short_counts[..],
     ]
     .concat();
-    let nr_arrangements = nr_arrangements(symbols, counts, false);
-    nr_arrangements
+
+    nr_arrangements(symbols, counts, false)
+}
+
+// The number of arrangements of the record as it is, without quintupling it, like in part a.
+pub fn nr_arrangements_for_folded_record((symbols, counts): &Record) -> u64 {
+    nr_arrangements(format!("{symbols}."), counts.clone(), false)
+}
+
+// The memoized function, wrapped to count how often the cache already has the answer. A call that
+// misses the cache adds its answer to the cache, and a call that hits it doesn't.
+pub fn nr_arrangements(symbols: String, counts: Vec<u32>, in_hashes: bool) -> u64 {
+    if !metrics::enabled() {
+        return cached_nr_arrangements(symbols, counts, in_hashes);
+    }
+    let cache_size = memoized_size_cached_nr_arrangements();
+    let arrangements = cached_nr_arrangements(symbols, counts, in_hashes);
+    if memoized_size_cached_nr_arrangements() == cache_size {
+        metrics::count("cache hits", 1);
+    } else {
+        metrics::count("cache misses", 1);
+    }
+    arrangements
 }
 
 #[memoize] // Memoization is essential for performance
-fn nr_arrangements(symbols: String, counts: Vec<u32>, in_hashes: bool) -> u64 {
+fn cached_nr_arrangements(symbols: String, counts: Vec<u32>, in_hashes: bool) -> u64 {
     if symbols.is_empty() {
         return if counts.is_empty() {
             // Symbols is empty and counts is also empty => 1 match
@@ -57,7 +75,8 @@ fn nr_arrangements(symbols: String, counts: Vec<u32>, in_hashes: bool) -> u64 {
     let first_symbol = symbols.chars().next().unwrap();
     let rest_symbols = String::from(&symbols[1..]);
     let first_count = counts[0];
-    let result = if first_symbol == '#' {
+
+    if first_symbol == '#' {
         // First symbol is #
         if in_hashes {
             // Continuing in group of #'s
@@ -100,10 +119,9 @@ fn nr_arrangements(symbols: String, counts: Vec<u32>, in_hashes: bool) -> u64 {
         let dot_symbol = format!(".{}", &rest_symbols);
         let hash_arrangements = nr_arrangements(dash_symbol, counts.clone(), in_hashes);
         let dot_arrangements = nr_arrangements(dot_symbol, counts.clone(), in_hashes);
-        let total_arrangements = hash_arrangements + dot_arrangements;
-        total_arrangements
+
+        hash_arrangements + dot_arrangements
     } else {
         panic!("Unexpected symbol: {}", first_symbol);
-    };
-    result
+    }
 }
@@ -0,0 +1,48 @@
+use aoc_common::{Answer, Grid, ParseError, Solution, Solver, blocks, solver};
+
+pub mod part_a;
+pub mod part_b;
+
+pub const SOLVER_A: Solver = solver! {
+    day: 13,
+    part: 'a',
+    description: "Total notes sum",
+    solution: Day13,
+};
+
+pub const SOLVER_B: Solver = solver! {
+    day: 13,
+    part: 'b',
+    description: "Total notes sum",
+    solution: Day13,
+};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Map>;
+
+    fn parse(input: &str) -> Result<Vec<Map>, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(input: &Vec<Map>) -> Option<Answer> {
+        Some(part_a::solve(input).into())
+    }
+
+    fn part_b(input: &Vec<Map>) -> Option<Answer> {
+        Some(part_b::solve(input).into())
+    }
+}
+
+pub type Map = Grid<char>;
+
+pub fn parse(input: &str) -> Result<Vec<Map>, ParseError> {
+    blocks(input).iter().map(|block| read_map(block)).collect()
+}
+
+fn read_map(block: &[&str]) -> Result<Map, ParseError> {
+    Grid::try_parse_lines(block.iter().copied(), "terrain", |c| {
+        "#.".contains(c).then_some(c)
+    })
+}
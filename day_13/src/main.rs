@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::run(&[day_13::SOLVER_A, day_13::SOLVER_B]);
+}
@@ -0,0 +1,53 @@
+use crate::Map;
+use aoc_common::assume;
+
+pub fn solve(maps: &[Map]) -> isize {
+    let mut notes_sum = 0;
+    for map in maps {
+        let notes = calculate_map_notes(map);
+        notes_sum += notes;
+    }
+    notes_sum
+}
+
+fn calculate_map_notes(map: &Map) -> isize {
+    for row in 0..(map.height() as isize) - 1 {
+        if is_mirror_below_row(map, row) {
+            return 100 * (row + 1); // +1 our row indexes are 0-based and the puzzle expects 1-based
+        }
+    }
+    for col in 0..(map.width() as isize) - 1 {
+        if is_mirror_right_of_col(map, col) {
+            return col + 1; // +1 our col indexes are 0-based and the puzzle expects 1-based
+        }
+    }
+    assume(false, "every map has a line of reflection");
+    unreachable!()
+}
+
+fn is_mirror_below_row(map: &Map, row: isize) -> bool {
+    let mut row_1 = row;
+    let mut row_2 = row + 1;
+    let nr_rows = map.height() as isize;
+    while row_1 >= 0 && row_2 < nr_rows {
+        if map.row(row_1 as usize) != map.row(row_2 as usize) {
+            return false;
+        }
+        row_1 -= 1;
+        row_2 += 1;
+    }
+    true
+}
+
+fn is_mirror_right_of_col(map: &Map, col: isize) -> bool {
+    let mut col_1 = col;
+    let mut col_2 = col + 1;
+    while col_1 >= 0 && col_2 < map.width() as isize {
+        if !map.column(col_1 as usize).eq(map.column(col_2 as usize)) {
+            return false;
+        }
+        col_1 -= 1;
+        col_2 += 1;
+    }
+    true
+}
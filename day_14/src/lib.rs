@@ -0,0 +1,110 @@
+use aoc_common::{
+    Animation, Answer, Grid, Paint, ParseError, Picture, Rgb, Solution, Solver, solver,
+};
+
+pub mod part_a;
+pub mod part_b;
+
+pub const SOLVER_A: Solver = solver! {
+    day: 14,
+    part: 'a',
+    description: "Load of map",
+    solution: Day14,
+};
+
+pub const SOLVER_B: Solver = solver! {
+    day: 14,
+    part: 'b',
+    description: "Load of map",
+    solution: Day14,
+};
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Map, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(input: &Map) -> Option<Answer> {
+        Some(part_a::solve(input).into())
+    }
+
+    fn part_b(input: &Map) -> Option<Answer> {
+        Some(part_b::solve(input).into())
+    }
+
+    fn render(input: &Map, part: char) -> Option<Picture> {
+        match part {
+            'a' => Some(part_a::render(input)),
+            'b' => Some(part_b::render(input)),
+            _ => None,
+        }
+    }
+
+    fn animate(input: &Map, part: char, animation: &mut Animation) -> Option<Answer> {
+        (part == 'b').then(|| part_b::animate(input, animation).into())
+    }
+}
+
+pub type Map = Grid<char>;
+
+pub fn parse(input: &str) -> Result<Map, ParseError> {
+    Grid::try_parse(input, "rock", |c| "O#.".contains(c).then_some(c))
+}
+
+fn rocks_picture(map: &Map) -> Picture {
+    let cells = map.map(|&c| match c {
+        'O' => Paint::Kind("round rock"),
+        '#' => Paint::Kind("cube rock"),
+        _ => Paint::Kind("empty"),
+    });
+    Picture {
+        cells,
+        palette: vec![
+            ("round rock", Rgb(0xc0, 0x60, 0x20)),
+            ("cube rock", Rgb(0x40, 0x40, 0x40)),
+            ("empty", Rgb(0xf0, 0xf0, 0xe0)),
+        ],
+    }
+}
+
+fn roll_rocks_north(map: &mut Map) {
+    for column_index in 0..map.width() {
+        roll_rocks_north_in_column(map, column_index);
+    }
+}
+
+fn roll_rocks_north_in_column(map: &mut Map, column_index: usize) {
+    let nr_rows = map.height();
+    let mut roll_to_row_index = 0;
+    for row_index in 0..nr_rows {
+        match map[(column_index, row_index)] {
+            '#' => roll_to_row_index = row_index + 1,
+            'O' => {
+                if row_index != roll_to_row_index {
+                    map[(column_index, roll_to_row_index)] = 'O';
+                    map[(column_index, row_index)] = '.';
+                }
+                roll_to_row_index += 1;
+            }
+            '.' => (),
+            _ => panic!("Unexpected character in map"),
+        }
+    }
+}
+
+fn compute_load_of_map(map: &Map) -> usize {
+    let mut load = 0;
+    let nr_rows = map.height();
+    for (row_nr, row) in map.rows().enumerate() {
+        for c in row {
+            if *c == 'O' {
+                load += nr_rows - row_nr;
+            }
+        }
+    }
+    load
+}
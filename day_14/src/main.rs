@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::run(&[day_14::SOLVER_A, day_14::SOLVER_B]);
+}
@@ -0,0 +1,15 @@
+use crate::{Map, compute_load_of_map, rocks_picture, roll_rocks_north};
+use aoc_common::Picture;
+
+pub fn solve(map: &Map) -> usize {
+    let mut map = map.clone();
+    roll_rocks_north(&mut map);
+    compute_load_of_map(&map)
+}
+
+// The rocks after tilting the platform north
+pub fn render(map: &Map) -> Picture {
+    let mut map = map.clone();
+    roll_rocks_north(&mut map);
+    rocks_picture(&map)
+}
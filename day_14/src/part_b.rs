@@ -0,0 +1,67 @@
+use crate::{Map, compute_load_of_map, rocks_picture, roll_rocks_north};
+use aoc_common::{Animation, Picture};
+use std::collections::HashMap;
+
+pub fn solve(map: &Map) -> usize {
+    let map = roll_rocks_many_cycles(map.clone(), 1_000_000_000, &mut |_| ());
+    compute_load_of_map(&map)
+}
+
+// The rocks after all the spin cycles
+pub fn render(map: &Map) -> Picture {
+    rocks_picture(&roll_rocks_many_cycles(
+        map.clone(),
+        1_000_000_000,
+        &mut |_| (),
+    ))
+}
+
+// The rocks after each spin cycle, until the cycles repeat and the rest of them are skipped
+pub fn animate(map: &Map, animation: &mut Animation) -> usize {
+    animation.frame(|| rocks_picture(map));
+    let map = roll_rocks_many_cycles(map.clone(), 1_000_000_000, &mut |map| {
+        animation.frame(|| rocks_picture(map))
+    });
+    compute_load_of_map(&map)
+}
+
+fn roll_rocks_cycle(map: Map) -> Map {
+    // Roll the rocks north, west, south and east. Rotating the map clockwise after rolling the
+    // rocks north turns the next direction into north, and after four turns the map is back in
+    // its original orientation.
+    let mut map = map;
+    for _ in 0..4 {
+        roll_rocks_north(&mut map);
+        map = map.rotate_clockwise();
+    }
+    map
+}
+
+// Calls on_cycle with the map after every spin cycle that is not skipped.
+fn roll_rocks_many_cycles(map: Map, nr_cycles: usize, on_cycle: &mut dyn FnMut(&Map)) -> Map {
+    let mut map = map.clone();
+    let mut seen_maps = HashMap::<Map, Vec<usize>>::new();
+    let mut cycle_nr = 0;
+    while cycle_nr < nr_cycles {
+        match seen_maps.get_mut(&map) {
+            Some(seen_in_cycles) => {
+                seen_in_cycles.push(cycle_nr);
+                for previous_cycle_nr in seen_in_cycles {
+                    let cycle_length = cycle_nr - *previous_cycle_nr;
+                    if cycle_nr + cycle_length < nr_cycles - 1 {
+                        cycle_nr += cycle_length;
+                        break;
+                    }
+                }
+            }
+            None => {
+                let seen_in_cycles = vec![cycle_nr];
+                seen_maps.insert(map.clone(), seen_in_cycles);
+            }
+        }
+        map = roll_rocks_cycle(map);
+        on_cycle(&map);
+        cycle_nr += 1;
+    }
+    map
+}
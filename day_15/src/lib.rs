@@ -0,0 +1,86 @@
+use aoc_common::{Answer, ParseError, Solution, Solver, parse_number, solver, split_once};
+
+pub mod part_a;
+pub mod part_b;
+
+pub const SOLVER_A: Solver = solver! {
+    day: 15,
+    part: 'a',
+    description: "Sum of hashes",
+    solution: Day15,
+};
+
+pub const SOLVER_B: Solver = solver! {
+    day: 15,
+    part: 'b',
+    description: "Total focusing power",
+    solution: Day15,
+};
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<Step>;
+
+    fn parse(input: &str) -> Result<Vec<Step>, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(input: &Vec<Step>) -> Option<Answer> {
+        Some(part_a::solve(input).into())
+    }
+
+    fn part_b(input: &Vec<Step>) -> Option<Answer> {
+        Some(part_b::solve(input).into())
+    }
+}
+
+// A step of the initialization sequence. Part a hashes the step as it is written.
+pub struct Step {
+    text: String,
+    label: String,
+    operation: Operation,
+}
+
+enum Operation {
+    Remove,
+    Insert { focal_length: u64 },
+}
+
+pub fn parse(input: &str) -> Result<Vec<Step>, ParseError> {
+    let line = input
+        .lines()
+        .next()
+        .ok_or_else(|| ParseError::missing("initialization sequence", &input[input.len()..]))?;
+    line.split(',').map(parse_step).collect()
+}
+
+fn parse_step(text: &str) -> Result<Step, ParseError> {
+    let (label, operation) = match text.strip_suffix('-') {
+        Some(label) => (label, Operation::Remove),
+        None => {
+            let (label, focal_length) = split_once(text, "=")?;
+            let focal_length = parse_number(focal_length)?;
+            (label, Operation::Insert { focal_length })
+        }
+    };
+    if label.is_empty() {
+        return Err(ParseError::missing("label", text));
+    }
+    Ok(Step {
+        text: text.to_string(),
+        label: label.to_string(),
+        operation,
+    })
+}
+
+fn hash(s: &str) -> u64 {
+    let bytes = s.as_bytes();
+    let mut hash: u64 = 0;
+    for byte in bytes {
+        hash += *byte as u64;
+        hash *= 17;
+        hash %= 256;
+    }
+    hash
+}
@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::run(&[day_15::SOLVER_A, day_15::SOLVER_B]);
+}
@@ -0,0 +1,9 @@
+use crate::{Step, hash};
+
+pub fn solve(init_seq: &[Step]) -> u64 {
+    sum_hashes(init_seq)
+}
+
+fn sum_hashes(init_seq: &[Step]) -> u64 {
+    init_seq.iter().fold(0, |acc, step| acc + hash(&step.text))
+}
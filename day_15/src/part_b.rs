@@ -0,0 +1,67 @@
+use crate::{Operation, Step, hash};
+
+struct Lens {
+    label: String,
+    focal_length: u64,
+}
+
+type Box = Vec<Lens>;
+
+type Boxes = Vec<Box>;
+
+const NR_BOXES: usize = 256;
+
+pub fn solve(init_seq: &[Step]) -> usize {
+    let mut boxes = Boxes::new();
+    for _ in 0..NR_BOXES {
+        boxes.push(Box::new());
+    }
+    run_init_seq(init_seq, &mut boxes);
+    total_focusing_power(&boxes)
+}
+
+fn run_init_seq(init_seq: &[Step], boxes: &mut Boxes) {
+    for step in init_seq {
+        run_step(step, boxes);
+    }
+}
+
+fn run_step(step: &Step, boxes: &mut Boxes) {
+    match step.operation {
+        Operation::Remove => remove_lens(&step.label, boxes),
+        Operation::Insert { focal_length } => add_lens(&step.label, focal_length, boxes),
+    }
+}
+
+fn remove_lens(label: &str, boxes: &mut Boxes) {
+    let hash = hash(label) as usize;
+    if let Some(pos) = boxes[hash].iter().position(|lens| lens.label == label) {
+        boxes[hash].remove(pos);
+    }
+}
+
+fn add_lens(label: &str, focal_length: u64, boxes: &mut Boxes) {
+    let lens = Lens {
+        label: label.to_string(),
+        focal_length,
+    };
+    let hash = hash(label) as usize;
+    for existing_lens in &mut boxes[hash] {
+        if existing_lens.label == label {
+            existing_lens.focal_length = focal_length;
+            return;
+        }
+    }
+    boxes[hash].push(lens);
+}
+
+fn total_focusing_power(boxes: &Boxes) -> usize {
+    let mut total = 0;
+    for (box_nr, the_box) in boxes.iter().enumerate() {
+        for (lens_nr, lens) in the_box.iter().enumerate() {
+            let focusing_power = (box_nr + 1) * (lens_nr + 1) * (lens.focal_length as usize);
+            total += focusing_power;
+        }
+    }
+    total
+}
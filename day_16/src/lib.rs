@@ -0,0 +1,182 @@
+use aoc_common::{
+    Animation, Answer, Dir, Grid, ParseError, Picture, Pos, Solution, Solver, metrics, solver,
+    trace_event,
+};
+
+pub mod part_a;
+pub mod part_b;
+
+pub const SOLVER_A: Solver = solver! {
+    day: 16,
+    part: 'a',
+    description: "Energized cells count",
+    solution: Day16,
+};
+
+pub const SOLVER_B: Solver = solver! {
+    day: 16,
+    part: 'b',
+    description: "Max energized cells count",
+    solution: Day16,
+};
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Map, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(input: &Map) -> Option<Answer> {
+        Some(part_a::solve(input).into())
+    }
+
+    fn part_b(input: &Map) -> Option<Answer> {
+        Some(part_b::solve(input).into())
+    }
+
+    fn render(input: &Map, part: char) -> Option<Picture> {
+        (part == 'a').then(|| part_a::render(input))
+    }
+
+    fn animate(input: &Map, part: char, animation: &mut Animation) -> Option<Answer> {
+        (part == 'a').then(|| part_a::animate(input, animation).into())
+    }
+}
+
+pub type Map = Grid<char>;
+
+// Positions and directions on the map are those of the geometry of aoc_common, where (0, 0) is the
+// top-left (North-West) corner of the map. Positions are signed, so that a laser that leaves the
+// map has a negative position, before we realize that it has moved off the map.
+
+// The visited map is a grid, where each cell contains a list of directions.
+// Each direction indicates that the cell has been visited coming from that direction.
+// The trail lists the visited cells in the order in which the laser visited them.
+struct VisitedMap {
+    grid: Grid<Vec<Dir>>,
+    trail: Vec<Pos>,
+}
+
+pub fn parse(input: &str) -> Result<Map, ParseError> {
+    Grid::try_parse(input, "tile", |c| ".\\/|-".contains(c).then_some(c))
+}
+
+fn energized_cells_count(visited_map: &VisitedMap) -> usize {
+    visited_map
+        .grid
+        .cells()
+        .filter(|cell| !cell.is_empty())
+        .count()
+}
+
+fn new_visited_map(for_map: &Map) -> VisitedMap {
+    VisitedMap {
+        grid: Grid::new(for_map.width(), for_map.height(), Vec::new()),
+        trail: Vec::new(),
+    }
+}
+
+fn follow_laser(map: &Map, visited_map: &mut VisitedMap, pos: &Pos, dir: &Dir) {
+    let _depth = metrics::enter("follow_laser depth");
+    // Do off map check here to avoid doing it in every follow_laser_in_* function
+    let Some(&tile) = map.get(pos.x, pos.y) else {
+        return;
+    };
+    trace_event!(
+        Trace,
+        "day16::beam",
+        "beam_entered",
+        x = pos.x,
+        y = pos.y,
+        dir = dir,
+        tile = tile,
+    );
+    if has_already_been_visited(visited_map, pos, dir) {
+        return;
+    }
+    mark_as_visited(visited_map, pos, dir);
+    metrics::count("beam steps", 1);
+    match tile {
+        '.' => follow_laser_in_empty_space(map, visited_map, pos, dir),
+        '/' | '\\' => follow_laser_in_mirror(map, visited_map, pos, dir, tile),
+        '|' => follow_laser_in_vertical_splitter(map, visited_map, pos, dir),
+        '-' => follow_laser_in_horizontal_splitter(map, visited_map, pos, dir),
+        _ => panic!("Invalid map character"),
+    }
+}
+
+fn follow_laser_in_empty_space(map: &Map, visited_map: &mut VisitedMap, pos: &Pos, dir: &Dir) {
+    follow_laser(map, visited_map, &(*pos + *dir), dir);
+}
+
+fn follow_laser_in_mirror(
+    map: &Map,
+    visited_map: &mut VisitedMap,
+    pos: &Pos,
+    dir: &Dir,
+    mirror: char,
+) {
+    let new_dir = dir.reflect(mirror);
+    follow_laser(map, visited_map, &(*pos + new_dir), &new_dir);
+}
+
+fn follow_laser_in_vertical_splitter(
+    map: &Map,
+    visited_map: &mut VisitedMap,
+    pos: &Pos,
+    dir: &Dir,
+) {
+    if dir.is_horizontal() {
+        // Coming from East or West, split into North and South
+        trace_event!(
+            Debug,
+            "day16::split",
+            "beam_split",
+            x = pos.x,
+            y = pos.y,
+            dir = dir
+        );
+        follow_laser(map, visited_map, &(*pos + Dir::N), &Dir::N);
+        follow_laser(map, visited_map, &(*pos + Dir::S), &Dir::S);
+    } else {
+        // Coming from North or South, continue in the same direction
+        follow_laser(map, visited_map, &(*pos + *dir), dir);
+    }
+}
+
+fn follow_laser_in_horizontal_splitter(
+    map: &Map,
+    visited_map: &mut VisitedMap,
+    pos: &Pos,
+    dir: &Dir,
+) {
+    if dir.is_vertical() {
+        // Coming from North or South, split into East and West
+        trace_event!(
+            Debug,
+            "day16::split",
+            "beam_split",
+            x = pos.x,
+            y = pos.y,
+            dir = dir
+        );
+        follow_laser(map, visited_map, &(*pos + Dir::E), &Dir::E);
+        follow_laser(map, visited_map, &(*pos + Dir::W), &Dir::W);
+    } else {
+        // Coming from East or West, continue in the same direction
+        follow_laser(map, visited_map, &(*pos + *dir), dir);
+    }
+}
+
+fn has_already_been_visited(visited_map: &VisitedMap, pos: &Pos, dir: &Dir) -> bool {
+    visited_map.grid[*pos].contains(dir)
+}
+
+fn mark_as_visited(visited_map: &mut VisitedMap, pos: &Pos, dir: &Dir) {
+    assert!(!visited_map.grid[*pos].contains(dir));
+    visited_map.grid[*pos].push(*dir);
+    visited_map.trail.push(*pos);
+}
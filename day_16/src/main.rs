@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::run(&[day_16::SOLVER_A, day_16::SOLVER_B]);
+}
@@ -0,0 +1,45 @@
+use crate::{Map, energized_cells_count, follow_laser, new_visited_map};
+use aoc_common::{Animation, Dir, Grid, Paint, Picture, Pos, Rgb};
+
+pub fn solve(map: &Map) -> usize {
+    let mut visited_map = new_visited_map(map);
+    follow_laser(map, &mut visited_map, &Pos::new(0, 0), &Dir::E);
+    energized_cells_count(&visited_map)
+}
+
+// The tiles that the beam energizes, with the mirrors and splitters on top
+pub fn render(map: &Map) -> Picture {
+    let mut visited_map = new_visited_map(map);
+    follow_laser(map, &mut visited_map, &Pos::new(0, 0), &Dir::E);
+    tiles_picture(map, &visited_map.grid.map(|cell| !cell.is_empty()))
+}
+
+// The tiles that the beam energizes, one more each time the laser reaches a tile
+pub fn animate(map: &Map, animation: &mut Animation) -> usize {
+    let mut visited_map = new_visited_map(map);
+    follow_laser(map, &mut visited_map, &Pos::new(0, 0), &Dir::E);
+    let mut energized = Grid::new(map.width(), map.height(), false);
+    for &pos in &visited_map.trail {
+        energized[pos] = true;
+        animation.frame(|| tiles_picture(map, &energized));
+    }
+    energized_cells_count(&visited_map)
+}
+
+fn tiles_picture(map: &Map, energized: &Grid<bool>) -> Picture {
+    let cells = map.positions().map(|pos| match map[pos] {
+        '/' | '\\' => Paint::Kind("mirror"),
+        '|' | '-' => Paint::Kind("splitter"),
+        _ if energized[pos] => Paint::Kind("energized"),
+        _ => Paint::Kind("empty"),
+    });
+    Picture {
+        cells: Grid::from_cells(map.width(), map.height(), cells.collect()),
+        palette: vec![
+            ("mirror", Rgb(0x20, 0x60, 0xc0)),
+            ("splitter", Rgb(0x80, 0x20, 0xa0)),
+            ("energized", Rgb(0xff, 0xd0, 0x30)),
+            ("empty", Rgb(0x20, 0x20, 0x20)),
+        ],
+    }
+}
@@ -0,0 +1,46 @@
+use crate::{Map, energized_cells_count, follow_laser, new_visited_map};
+use aoc_common::{Dir, Pos};
+
+pub fn solve(map: &Map) -> usize {
+    try_all_starting_points(map)
+}
+
+fn try_all_starting_points(map: &Map) -> usize {
+    let mut max_energized_count = 0;
+    // Northern edge (going South)
+    for x in 0..map.width() {
+        let energized_count = try_one_starting_point(map, Pos::new(x as isize, 0), Dir::S);
+        max_energized_count = max_energized_count.max(energized_count);
+    }
+    // Southern edge (going North)
+    for x in 0..map.width() {
+        let energized_count = try_one_starting_point(
+            map,
+            Pos::new(x as isize, (map.height() - 1) as isize),
+            Dir::N,
+        );
+        max_energized_count = max_energized_count.max(energized_count);
+    }
+    // Western edge (going East)
+    for y in 0..map.height() {
+        let energized_count = try_one_starting_point(map, Pos::new(0, y as isize), Dir::E);
+        max_energized_count = max_energized_count.max(energized_count);
+    }
+    // Eastern edge (going West)
+    for y in 0..map.height() {
+        let energized_count = try_one_starting_point(
+            map,
+            Pos::new((map.width() - 1) as isize, y as isize),
+            Dir::W,
+        );
+        max_energized_count = max_energized_count.max(energized_count);
+    }
+    max_energized_count
+}
+
+fn try_one_starting_point(map: &Map, start_pos: Pos, start_dir: Dir) -> usize {
+    let mut visited_map = new_visited_map(map);
+    follow_laser(map, &mut visited_map, &start_pos, &start_dir);
+
+    energized_cells_count(&visited_map)
+}
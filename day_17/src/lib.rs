@@ -0,0 +1,168 @@
+// Algorithm taken from https://www.reddit.com/r/adventofcode/comments/18luw6q/2023_day_17_a_longform_tutorial_on_day_17/
+
+use aoc_common::{Answer, Dir, Grid, ParseError, Pos, Solution, Solver, assume, metrics, solver};
+use std::collections::{HashMap, HashSet};
+
+pub mod part_a;
+pub mod part_b;
+
+pub const SOLVER_A: Solver = solver! {
+    day: 17,
+    part: 'a',
+    description: "Minimal heat loss",
+    solution: Day17,
+};
+
+pub const SOLVER_B: Solver = solver! {
+    day: 17,
+    part: 'b',
+    description: "Minimal heat loss",
+    solution: Day17,
+};
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Grid<usize>;
+
+    fn parse(input: &str) -> Result<Grid<usize>, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(input: &Grid<usize>) -> Option<Answer> {
+        Some(part_a::solve(input).into())
+    }
+
+    fn part_b(input: &Grid<usize>) -> Option<Answer> {
+        Some(part_b::solve(input).into())
+    }
+}
+
+pub fn parse(input: &str) -> Result<Grid<usize>, ParseError> {
+    let grid = Grid::try_parse(input, "heat loss", |c| c.to_digit(10).map(|d| d as usize))?;
+    if grid.width() == 0 || grid.height() == 0 {
+        return Err(ParseError::missing("city blocks", &input[..0]));
+    }
+    Ok(grid)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct State {
+    pos: Pos,
+    dir: Dir,
+    distance: usize,
+}
+
+struct Search<'a> {
+    grid: &'a Grid<usize>,
+    // The crucible must go this far straight before it can turn or stop
+    min_distance: usize,
+    states_by_cost: HashMap<usize, Vec<State>>,
+    seen_states: HashSet<State>,
+}
+
+// The minimal heat loss from the top-left to the bottom-right of the grid, for a crucible that goes
+// straight for at least min_distance and at most max_distance blocks at a time
+fn minimal_heat_loss(grid: &Grid<usize>, min_distance: usize, max_distance: usize) -> usize {
+    let mut search = Search {
+        grid,
+        min_distance,
+        states_by_cost: HashMap::new(),
+        seen_states: HashSet::new(),
+    };
+
+    // We don't know which way we'll start, so try both
+    // The instructions say to ignore the starting cost
+    if let Some(cost) = search.move_and_add_state(0, Pos::new(0, 0), Dir::E, 1) {
+        return cost;
+    }
+    if let Some(cost) = search.move_and_add_state(0, Pos::new(0, 0), Dir::S, 1) {
+        return cost;
+    }
+
+    // Iterate till we find the exit
+    loop {
+        // Find the horizon of our search, the states with the lowest cost
+        // All future states will have at least this value, so we can just pop
+        // Note: this assumes all grid values are positive!
+
+        // Get the lowest cost. Without any states left, the crucible cannot reach the end.
+        let lowest_cost = search.states_by_cost.keys().min();
+        assume(
+            lowest_cost.is_some(),
+            "the crucible can reach the bottom-right block",
+        );
+        let current_cost = *lowest_cost.unwrap();
+
+        // Get all states at that cost
+        let next_states = search.states_by_cost.remove(&current_cost).unwrap();
+
+        // Process each state
+        metrics::count("states explored", next_states.len() as u64);
+        for state in next_states {
+            let State { pos, dir, distance } = state;
+
+            // Perform left and right turns, if we can
+            if distance >= min_distance {
+                if let Some(cost) = search.move_and_add_state(current_cost, pos, dir.turn_left(), 1)
+                {
+                    return cost;
+                }
+                if let Some(cost) =
+                    search.move_and_add_state(current_cost, pos, dir.turn_right(), 1)
+                {
+                    return cost;
+                }
+            }
+
+            // Go straight, if we haven't gone too far already
+            if distance < max_distance
+                && let Some(cost) = search.move_and_add_state(current_cost, pos, dir, distance + 1)
+            {
+                return cost;
+            }
+        }
+    }
+}
+
+impl Search<'_> {
+    // Returns the cost of the path if the move reaches the end
+    fn move_and_add_state(
+        &mut self,
+        cost: usize,
+        pos: Pos,
+        dir: Dir,
+        distance: usize,
+    ) -> Option<usize> {
+        // Update the position
+        let pos = pos + dir;
+
+        // Calculate the cost of stepping on this square, if it is on the grid
+        let new_cost = cost + self.grid.get(pos.x, pos.y)?;
+
+        // Did we find the end?
+        let end = Pos::new(
+            self.grid.width() as isize - 1,
+            self.grid.height() as isize - 1,
+        );
+        if pos == end && distance >= self.min_distance {
+            return Some(new_cost);
+        }
+
+        // Create the state
+        let state = State { pos, dir, distance };
+
+        // Have we seen this state before?
+        if self.seen_states.contains(&state) {
+            metrics::count("states already seen", 1);
+        } else {
+            // Save the state to visit later
+            self.states_by_cost.entry(new_cost).or_default().push(state);
+
+            // Mark the state as seen
+            self.seen_states.insert(state);
+            metrics::count("states seen", 1);
+        }
+        None
+    }
+}
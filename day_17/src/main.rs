@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::run(&[day_17::SOLVER_A, day_17::SOLVER_B]);
+}
@@ -0,0 +1,6 @@
+use crate::minimal_heat_loss;
+use aoc_common::Grid;
+
+pub fn solve(grid: &Grid<usize>) -> usize {
+    minimal_heat_loss(grid, 1, 3)
+}
@@ -0,0 +1,7 @@
+use crate::minimal_heat_loss;
+use aoc_common::Grid;
+
+// An ultra crucible goes straight for at least four and at most ten blocks
+pub fn solve(grid: &Grid<usize>) -> usize {
+    minimal_heat_loss(grid, 4, 10)
+}
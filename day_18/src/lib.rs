@@ -0,0 +1,104 @@
+use aoc_common::{
+    Answer, Dir, ParseError, Picture, Solution, Solver, parse_number, solver, strip_prefix,
+};
+
+pub mod part_a;
+pub mod part_b;
+
+pub const SOLVER_A: Solver = solver! {
+    day: 18,
+    part: 'a',
+    description: "Volume",
+    solution: Day18,
+};
+
+pub const SOLVER_B: Solver = solver! {
+    day: 18,
+    part: 'b',
+    description: "Area",
+    solution: Day18,
+};
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Plan;
+
+    fn parse(input: &str) -> Result<Plan, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(input: &Plan) -> Option<Answer> {
+        Some(part_a::solve(input).into())
+    }
+
+    fn part_b(input: &Plan) -> Option<Answer> {
+        Some(part_b::solve(input).into())
+    }
+
+    fn render(input: &Plan, part: char) -> Option<Picture> {
+        (part == 'a').then(|| part_a::render(input))
+    }
+}
+
+// The plan as it is written, and as it is decoded from the hexadecimal colors for part b
+pub struct Plan {
+    steps: Vec<Step>,
+    hex_steps: Vec<(Dir, isize)>,
+}
+
+// Up is North on the map, so y goes down, as in the picture
+#[derive(Debug, Hash)]
+pub struct Step {
+    direction: Dir,
+    length: usize,
+    color: String,
+}
+
+pub fn parse(input: &str) -> Result<Plan, ParseError> {
+    let mut steps = Vec::new();
+    let mut hex_steps = Vec::new();
+    for line in input.lines() {
+        let mut parts = line.split_whitespace();
+        let mut next_part = |what| parts.next().ok_or_else(|| ParseError::missing(what, line));
+        let direction_str = next_part("direction")?;
+        let direction = match direction_str {
+            "U" => Dir::N,
+            "D" => Dir::S,
+            "L" => Dir::W,
+            "R" => Dir::E,
+            _ => return Err(ParseError::unknown("direction", direction_str)),
+        };
+        let length: usize = parse_number(next_part("length")?)?;
+        let color = next_part("color")?.to_string();
+        hex_steps.push(parse_hex_step(&color)?);
+        let step = Step {
+            direction,
+            length,
+            color,
+        };
+        steps.push(step);
+    }
+    Ok(Plan { steps, hex_steps })
+}
+
+// The direction and length that a color such as (#70c710) encodes
+fn parse_hex_step(color: &str) -> Result<(Dir, isize), ParseError> {
+    let hex = strip_prefix(color, "(#")?;
+    let hex = hex
+        .strip_suffix(')')
+        .ok_or_else(|| ParseError::expected(")", hex))?;
+    let (length_hex, direction) = hex
+        .split_at_checked(5)
+        .ok_or_else(|| ParseError::missing("direction", hex))?;
+    let length = isize::from_str_radix(length_hex, 16)
+        .map_err(|_| ParseError::invalid_number(length_hex))?;
+    let direction = match direction {
+        "0" => Dir::E,
+        "1" => Dir::S,
+        "2" => Dir::W,
+        "3" => Dir::N,
+        _ => return Err(ParseError::unknown("direction", direction)),
+    };
+    Ok((direction, length))
+}
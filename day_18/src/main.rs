@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::run(&[day_18::SOLVER_A, day_18::SOLVER_B]);
+}
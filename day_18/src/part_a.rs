@@ -0,0 +1,149 @@
+use crate::{Plan, Step};
+use aoc_common::{Grid, Paint, Picture, Pos, Rgb};
+
+// The top-left and bottom-right corners of the trench, relative to the start
+#[derive(Debug)]
+struct Extent {
+    min: Pos,
+    max: Pos,
+}
+
+type Map = Vec<Vec<bool>>;
+
+pub fn solve(plan: &Plan) -> usize {
+    let mut map = make_map(plan);
+    flood_fill_interior(&mut map);
+    measure_volume(&map)
+}
+
+// The lagoon, with the trench around it in the colors of the plan
+pub fn render(plan: &Plan) -> Picture {
+    let mut lagoon = make_map(plan);
+    flood_fill_interior(&mut lagoon);
+    let size_y = lagoon.len();
+    let size_x = lagoon[0].len();
+    let mut cells = Grid::new(size_x, size_y, Paint::Kind("ground"));
+    for (y, row) in lagoon.iter().enumerate() {
+        for (x, &dug) in row.iter().enumerate() {
+            if dug {
+                cells[(x, y)] = Paint::Kind("lagoon");
+            }
+        }
+    }
+    dig_trench(plan, |pos, step| {
+        let color = step.color.trim_start_matches('(').trim_end_matches(')');
+        cells[pos] = Rgb::parse(color).map_or(Paint::Kind("trench"), Paint::Color);
+    });
+    Picture {
+        cells,
+        palette: vec![
+            ("ground", Rgb(0xe0, 0xd0, 0xb0)),
+            ("lagoon", Rgb(0x60, 0x40, 0x30)),
+            ("trench", Rgb(0x00, 0x00, 0x00)),
+        ],
+    }
+}
+
+fn make_map(plan: &Plan) -> Map {
+    let extent = determine_extent(plan);
+    let size_x = extent.max.x - extent.min.x + 1;
+    let size_y = extent.max.y - extent.min.y + 1;
+    let mut map = empty_map(size_x as usize, size_y as usize);
+    dig_trench(plan, |pos, _step| {
+        map[pos.y as usize][pos.x as usize] = true
+    });
+    map
+}
+
+// Follow the plan, calling dig with each position of the trench on the map and the step that digs it
+fn dig_trench(plan: &Plan, mut dig: impl FnMut(Pos, &Step)) {
+    let extent = determine_extent(plan);
+    let mut pos = Pos::new(-extent.min.x, -extent.min.y);
+    for step in &plan.steps {
+        for _ in 0..step.length {
+            pos += step.direction;
+            dig(pos, step);
+        }
+    }
+}
+
+fn determine_extent(plan: &Plan) -> Extent {
+    let mut pos = Pos::default();
+    let mut min = pos;
+    let mut max = pos;
+    for step in &plan.steps {
+        pos = pos.steps(step.direction, step.length as isize);
+        min = Pos::new(min.x.min(pos.x), min.y.min(pos.y));
+        max = Pos::new(max.x.max(pos.x), max.y.max(pos.y));
+    }
+    Extent { min, max }
+}
+
+fn empty_map(size_x: usize, size_y: usize) -> Map {
+    vec![vec![false; size_x]; size_y]
+}
+
+fn flood_fill_interior(map: &mut Map) {
+    let exterior = flood_fill_exterior(map);
+    let size_y = map.len();
+    let size_x = map[0].len();
+    for y in 0..size_y {
+        for x in 0..size_x {
+            if !exterior[y][x] && !map[y][x] {
+                map[y][x] = true;
+            }
+        }
+    }
+}
+
+fn flood_fill_exterior(map: &Map) -> Map {
+    let size_y = map.len();
+    let size_x = map[0].len();
+    let mut exterior = empty_map(size_x, size_y);
+    let mut stack = Vec::new();
+    for x in 0..size_x {
+        stack.push((x, 0usize));
+        stack.push((x, size_y - 1));
+    }
+    for y in 0..size_y {
+        stack.push((0usize, y));
+        stack.push((size_x - 1, y));
+    }
+    while let Some((x, y)) = stack.pop() {
+        if x >= size_x || y >= size_y {
+            continue;
+        }
+        if exterior[y][x] {
+            continue;
+        }
+        if map[y][x] {
+            continue;
+        }
+        exterior[y][x] = true;
+        if x > 0 {
+            stack.push((x - 1, y));
+        }
+        if x + 1 < size_x {
+            stack.push((x + 1, y));
+        }
+        if y > 0 {
+            stack.push((x, y - 1));
+        }
+        if y + 1 < size_y {
+            stack.push((x, y + 1));
+        }
+    }
+    exterior
+}
+
+fn measure_volume(map: &Map) -> usize {
+    let mut volume = 0;
+    for row in map {
+        for &cell in row {
+            if cell {
+                volume += 1;
+            }
+        }
+    }
+    volume
+}
@@ -0,0 +1,20 @@
+// See analysis.pptx for explanation
+
+use crate::Plan;
+use aoc_common::{Pos, shoelace_double_area};
+
+// By Pick's theorem, the area of the polygon through the middle of the trench is the number of
+// cubes strictly inside it plus half the cubes on it, minus one.
+pub fn solve(plan: &Plan) -> isize {
+    let mut corners = Vec::new();
+    let mut pos = Pos::default();
+    let mut exterior: isize = 0;
+    for &(direction, length) in &plan.hex_steps {
+        pos = pos.steps(direction, length);
+        exterior += length;
+        corners.push(pos);
+    }
+    let double_area = shoelace_double_area(&corners);
+    let interior = (double_area - exterior) / 2 + 1;
+    interior + exterior
+}
@@ -0,0 +1,228 @@
+use aoc_common::{
+    Answer, ParseError, Solution, Solver, parse_number, solver, split_once, strip_prefix,
+};
+use std::collections::hash_map::HashMap;
+
+pub mod part_a;
+pub mod part_b;
+
+pub const SOLVER_A: Solver = solver! {
+    day: 19,
+    part: 'a',
+    description: "Sum of ratings of all accepted parts",
+    solution: Day19,
+};
+
+pub const SOLVER_B: Solver = solver! {
+    day: 19,
+    part: 'b',
+    description: "Number of matching parts",
+    solution: Day19,
+};
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = (WorkflowSet, PartSet);
+
+    fn parse(input: &str) -> Result<(WorkflowSet, PartSet), ParseError> {
+        parse(input)
+    }
+
+    fn part_a(input: &(WorkflowSet, PartSet)) -> Option<Answer> {
+        Some(part_a::solve(input).into())
+    }
+
+    fn part_b(input: &(WorkflowSet, PartSet)) -> Option<Answer> {
+        Some(part_b::solve(input).into())
+    }
+}
+
+#[derive(Debug)]
+enum Comparison {
+    LessThan,
+    GreaterThan,
+}
+
+#[derive(Clone, Debug)]
+enum Action {
+    Accept,
+    Reject,
+    Workflow(String),
+}
+
+impl Action {
+    fn from_str(s: &str) -> Action {
+        if s == "A" {
+            Action::Accept
+        } else if s == "R" {
+            Action::Reject
+        } else {
+            Action::Workflow(s.to_string())
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Rule {
+    rule_str: String, // Kept for tracing
+    category: char,
+    comparison: Comparison,
+    value: usize,
+    action: Action,
+}
+
+// Parsing a workflow also collects the targets of its actions, so that parse can check that they are
+// defined once it has seen all the workflows.
+impl Rule {
+    fn from_str<'a>(rule_str: &'a str, targets: &mut Vec<&'a str>) -> Result<Rule, ParseError> {
+        let (condition_str, action_str) = split_once(rule_str.trim(), ":")?;
+        targets.push(action_str);
+        let (category_str, condition_str) = condition_str
+            .split_at_checked(1)
+            .ok_or_else(|| ParseError::missing("category", condition_str))?;
+        let category = parse_category(category_str)?;
+        let (comparison_str, value_str) = condition_str
+            .split_at_checked(1)
+            .ok_or_else(|| ParseError::missing("comparison operator", condition_str))?;
+        let comparison = match comparison_str {
+            "<" => Comparison::LessThan,
+            ">" => Comparison::GreaterThan,
+            _ => return Err(ParseError::unknown("comparison operator", comparison_str)),
+        };
+        let value = parse_number(value_str)?;
+        let action = Action::from_str(action_str);
+        Ok(Rule {
+            rule_str: rule_str.to_string(),
+            category,
+            comparison,
+            value,
+            action,
+        })
+    }
+}
+
+fn parse_category(s: &str) -> Result<char, ParseError> {
+    match s {
+        "x" => Ok('x'),
+        "m" => Ok('m'),
+        "a" => Ok('a'),
+        "s" => Ok('s'),
+        _ => Err(ParseError::unknown("category", s)),
+    }
+}
+
+#[derive(Debug)]
+struct Workflow {
+    name: String,
+    rules: Vec<Rule>,
+    default_action: Action,
+}
+
+impl Workflow {
+    fn from_string<'a>(s: &'a str, targets: &mut Vec<&'a str>) -> Result<Workflow, ParseError> {
+        let (name, rules_str) = split_once(s, "{")?;
+        let name = name.to_string();
+        let inside_curly_str = rules_str
+            .strip_suffix('}')
+            .ok_or_else(|| ParseError::expected("}", rules_str))?;
+        let part_str_vec = inside_curly_str.split(',').collect::<Vec<&str>>();
+        let default_action_str = part_str_vec.last().unwrap().trim();
+        targets.push(default_action_str);
+        let default_action = Action::from_str(default_action_str);
+        let rule_str_vec = &part_str_vec[..part_str_vec.len() - 1];
+        let mut rules: Vec<Rule> = Vec::new();
+        for rule_str in rule_str_vec {
+            let rule = Rule::from_str(rule_str, targets)?;
+            rules.push(rule);
+        }
+        Ok(Workflow {
+            name,
+            rules,
+            default_action,
+        })
+    }
+}
+
+pub struct WorkflowSet {
+    workflows: Vec<Workflow>,
+    name_to_index: HashMap<String, usize>,
+}
+
+impl WorkflowSet {
+    fn new() -> WorkflowSet {
+        WorkflowSet {
+            workflows: Vec::new(),
+            name_to_index: HashMap::new(),
+        }
+    }
+
+    fn add_workflow(&mut self, workflow: Workflow) {
+        let index = self.workflows.len();
+        self.name_to_index.insert(workflow.name.clone(), index);
+        self.workflows.push(workflow);
+    }
+}
+
+#[derive(Debug)]
+pub struct Part {
+    x: usize,
+    m: usize,
+    a: usize,
+    s: usize,
+}
+
+impl Part {
+    fn from_string(s: &str) -> Result<Part, ParseError> {
+        let ratings_str = strip_prefix(s, "{")?;
+        let ratings_str = ratings_str
+            .strip_suffix('}')
+            .ok_or_else(|| ParseError::expected("}", ratings_str))?;
+        let mut ratings = ratings_str.split(',');
+        let mut next_rating = |prefix| match ratings.next() {
+            Some(rating) => Self::parse_assignment(rating, prefix),
+            None => Err(ParseError::missing("rating", ratings_str)),
+        };
+        Ok(Part {
+            x: next_rating("x=")?,
+            m: next_rating("m=")?,
+            a: next_rating("a=")?,
+            s: next_rating("s=")?,
+        })
+    }
+
+    fn parse_assignment(s: &str, prefix: &'static str) -> Result<usize, ParseError> {
+        parse_number(strip_prefix(s, prefix)?)
+    }
+}
+
+pub type PartSet = Vec<Part>;
+
+pub fn parse(input: &str) -> Result<(WorkflowSet, PartSet), ParseError> {
+    let mut workflows: WorkflowSet = WorkflowSet::new();
+    let mut parts: PartSet = Vec::new();
+    let mut targets = Vec::new();
+    let mut reading_workflows = true;
+    for line in input.lines() {
+        if reading_workflows {
+            if line.trim().is_empty() {
+                reading_workflows = false;
+            } else {
+                let workflow = Workflow::from_string(line, &mut targets)?;
+                workflows.add_workflow(workflow);
+            }
+        } else {
+            let part = Part::from_string(line)?;
+            parts.push(part);
+        }
+    }
+    if !workflows.name_to_index.contains_key("in") {
+        return Err(ParseError::missing("workflow 'in'", &input[..0]));
+    }
+    for target in targets {
+        if target != "A" && target != "R" && !workflows.name_to_index.contains_key(target) {
+            return Err(ParseError::unknown("workflow", target));
+        }
+    }
+    Ok((workflows, parts))
+}
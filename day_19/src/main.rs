@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::run(&[day_19::SOLVER_A, day_19::SOLVER_B]);
+}
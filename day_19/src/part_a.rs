@@ -0,0 +1,70 @@
+use crate::{Action, Comparison, Part, PartSet, Rule, Workflow, WorkflowSet};
+
+impl Rule {
+    fn apply_to_part(&self, part: &Part) -> Option<Action> {
+        let part_value = part.get_category_value(self.category);
+        let condition_satisfied = match self.comparison {
+            Comparison::LessThan => part_value < self.value,
+            Comparison::GreaterThan => part_value > self.value,
+        };
+        if condition_satisfied {
+            Some(self.action.clone())
+        } else {
+            None
+        }
+    }
+}
+
+impl Workflow {
+    fn apply_to_part(&self, part: &Part) -> Action {
+        for rule in &self.rules {
+            if let Some(action) = rule.apply_to_part(part) {
+                return action;
+            }
+        }
+        self.default_action.clone()
+    }
+}
+
+impl WorkflowSet {
+    pub fn is_part_accepted(&self, part: &Part) -> bool {
+        let mut workflow_index = self.name_to_index["in"];
+        loop {
+            let workflow = self.workflows.get(workflow_index).unwrap();
+            let action = workflow.apply_to_part(part);
+            match action {
+                Action::Accept => return true,
+                Action::Reject => return false,
+                Action::Workflow(workflow_name) => {
+                    workflow_index = self.name_to_index[&workflow_name]
+                }
+            }
+        }
+    }
+}
+
+impl Part {
+    fn get_category_value(&self, category: char) -> usize {
+        match category {
+            'x' => self.x,
+            'm' => self.m,
+            'a' => self.a,
+            's' => self.s,
+            _ => panic!("Unknown category {category}"),
+        }
+    }
+
+    pub fn total_rating(&self) -> usize {
+        self.x + self.m + self.a + self.s
+    }
+}
+
+pub fn solve((workflows, parts): &(WorkflowSet, PartSet)) -> usize {
+    let mut total_ratings_sum = 0;
+    for part in parts {
+        if workflows.is_part_accepted(part) {
+            total_ratings_sum += part.total_rating();
+        }
+    }
+    total_ratings_sum
+}
@@ -1,116 +1,9 @@
+use crate::{Action, Comparison, PartSet, Rule, WorkflowSet};
+use aoc_common::trace_event;
 use std::collections::hash_map::HashMap;
 use std::fmt;
-use std::fs::read_to_string;
-
-#[derive(Debug)]
-enum Comparison {
-    LessThan,
-    GreaterThan,
-}
-
-#[derive(Clone, Debug)]
-enum Action {
-    Accept,
-    Reject,
-    Workflow(String),
-}
-
-impl Action {
-    fn from_str(s: &str) -> Action {
-        if s == "A" {
-            Action::Accept
-        } else if s == "R" {
-            Action::Reject
-        } else {
-            Action::Workflow(s.to_string())
-        }
-    }
-}
-
-#[derive(Debug)]
-struct Rule {
-    #[allow(dead_code)] // rule_str is kept for debugging purposes
-    rule_str: String,
-    category: char,
-    comparison: Comparison,
-    value: usize,
-    action: Action,
-}
-
-impl Rule {
-    fn from_str(rule_str: &str) -> Rule {
-        let segments = rule_str.trim().split(':').collect::<Vec<&str>>();
-        assert!(segments.len() == 2);
-        let condition_str = segments[0];
-        let category = condition_str.chars().next().unwrap();
-        assert!("xmas".contains(category));
-        let comparison_char = condition_str.chars().nth(1).unwrap();
-        let comparison = match comparison_char {
-            '<' => Comparison::LessThan,
-            '>' => Comparison::GreaterThan,
-            _ => panic!("Invalid comparison operator"),
-        };
-        let value = condition_str[2..].parse().unwrap();
-        let action_str = segments[1];
-        let action = Action::from_str(action_str);
-        Rule {
-            rule_str: rule_str.to_string(),
-            category,
-            comparison,
-            value,
-            action,
-        }
-    }
-}
-
-#[derive(Debug)]
-struct Workflow {
-    name: String,
-    rules: Vec<Rule>,
-    default_action: Action,
-}
-
-impl Workflow {
-    fn from_string(s: &str) -> Workflow {
-        let open_curly_pos = s.find('{').unwrap();
-        let name = s[..open_curly_pos].to_string();
-        let inside_curly_str = &s[open_curly_pos + 1..s.len() - 1];
-        let part_str_vec = inside_curly_str.split(',').collect::<Vec<&str>>();
-        let default_action_str = part_str_vec.last().unwrap().trim();
-        let default_action = Action::from_str(default_action_str);
-        let rule_str_vec = &part_str_vec[..part_str_vec.len() - 1];
-        let mut rules: Vec<Rule> = Vec::new();
-        for rule_str in rule_str_vec {
-            let rule = Rule::from_str(rule_str);
-            rules.push(rule);
-        }
-        Workflow {
-            name,
-            rules,
-            default_action,
-        }
-    }
-}
-
-struct WorkflowSet {
-    workflows: Vec<Workflow>,
-    name_to_index: HashMap<String, usize>,
-}
 
 impl WorkflowSet {
-    fn new() -> WorkflowSet {
-        WorkflowSet {
-            workflows: Vec::new(),
-            name_to_index: HashMap::new(),
-        }
-    }
-
-    fn add_workflow(&mut self, workflow: Workflow) {
-        let index = self.workflows.len();
-        self.name_to_index.insert(workflow.name.clone(), index);
-        self.workflows.push(workflow);
-    }
-
     fn match_workflow(
         &self,
         workflow_name: &String,
@@ -120,9 +13,25 @@ impl WorkflowSet {
         let workflow = &self.workflows[workflow_index];
         let mut workflow_match_partition_set = PartitionSet::no_parts();
         let mut rest_partition_set = in_partition_set.clone();
+        trace_event!(
+            Trace,
+            "day19::partition",
+            "workflow_entered",
+            workflow = workflow_name,
+            parts = in_partition_set.number_of_matches(),
+        );
         for rule in &workflow.rules {
             let (rule_match_partition_set, rule_not_match_partition_set) =
                 rest_partition_set.split(rule);
+            trace_event!(
+                Debug,
+                "day19::partition",
+                "rule_split",
+                workflow = workflow_name,
+                rule = rule.rule_str,
+                matched = rule_match_partition_set.number_of_matches(),
+                rest = rule_not_match_partition_set.number_of_matches(),
+            );
             rest_partition_set = rule_not_match_partition_set;
             workflow_match_partition_set = self.apply_action_to_partition_set(
                 &rule.action,
@@ -155,11 +64,11 @@ impl WorkflowSet {
         }
     }
 
-    fn count_matching_parts(&self) -> usize {
+    pub fn count_matching_parts(&self) -> u64 {
         let in_partition_set = PartitionSet::all_parts();
         let match_partition = self.match_workflow(&"in".to_string(), &in_partition_set);
-        let count = match_partition.number_of_matches();
-        count
+
+        match_partition.number_of_matches()
     }
 }
 
@@ -202,24 +111,23 @@ impl Partition {
         Partition { category_ranges }
     }
 
+    // The category starts out without any ranges, rather than missing, since a split can leave it
+    // without any ranges at all
     fn clone_except_category(&self, category: char) -> Partition {
-        let mut new_category_ranges = HashMap::new();
-        for (&cat, ranges) in &self.category_ranges {
-            if cat != category {
-                new_category_ranges.insert(cat, ranges.clone());
-            }
-        }
+        let mut new_category_ranges = self.category_ranges.clone();
+        new_category_ranges.insert(category, Vec::new());
         Partition {
             category_ranges: new_category_ranges,
         }
     }
 
-    fn number_of_matches(&self) -> usize {
+    // Up to 4000 ratings in each of four categories, which overflows a 32-bit usize
+    fn number_of_matches(&self) -> u64 {
         let mut total = 1;
         for ranges in self.category_ranges.values() {
             let mut category_total = 0;
             for (start, end) in ranges {
-                category_total += end - start + 1;
+                category_total += (end - start + 1) as u64;
             }
             total *= category_total;
         }
@@ -235,14 +143,14 @@ impl Partition {
                 match_partition
                     .category_ranges
                     .entry(rule.category)
-                    .or_insert(Vec::new())
+                    .or_default()
                     .push(mr);
             }
             if let Some(nmr) = non_match_range {
                 non_match_partition
                     .category_ranges
                     .entry(rule.category)
-                    .or_insert(Vec::new())
+                    .or_default()
                     .push(nmr);
             }
         }
@@ -356,7 +264,7 @@ impl PartitionSet {
         }
     }
 
-    fn number_of_matches(&self) -> usize {
+    fn number_of_matches(&self) -> u64 {
         self.partitions.iter().map(|p| p.number_of_matches()).sum()
     }
 
@@ -380,21 +288,6 @@ impl PartitionSet {
     }
 }
 
-fn read_workflow_set() -> WorkflowSet {
-    let lines = read_to_string("puzzle_input").unwrap();
-    let mut workflows: WorkflowSet = WorkflowSet::new();
-    for line in lines.lines() {
-        if line.trim().is_empty() {
-            return workflows;
-        }
-        let workflow = Workflow::from_string(&line);
-        workflows.add_workflow(workflow);
-    }
-    panic!("No empty line separating workflows and parts");
-}
-
-fn main() {
-    let workflow_set = read_workflow_set();
-    let count = workflow_set.count_matching_parts();
-    println!("Number of matching parts: {}", count);
+pub fn solve((workflow_set, _parts): &(WorkflowSet, PartSet)) -> u64 {
+    workflow_set.count_matching_parts()
 }
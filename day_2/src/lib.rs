@@ -0,0 +1,78 @@
+use aoc_common::{
+    Answer, ParseError, Solution, Solver, parse_number, solver, split_once, strip_prefix,
+};
+
+pub mod part_a;
+pub mod part_b;
+
+pub const SOLVER_A: Solver = solver! {
+    day: 2,
+    part: 'a',
+    description: "Sum of game IDs",
+    solution: Day2,
+};
+
+pub const SOLVER_B: Solver = solver! {
+    day: 2,
+    part: 'b',
+    description: "Sum of powers",
+    solution: Day2,
+};
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Vec<Game>, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(input: &Vec<Game>) -> Option<Answer> {
+        Some(part_a::solve(input).into())
+    }
+
+    fn part_b(input: &Vec<Game>) -> Option<Answer> {
+        Some(part_b::solve(input).into())
+    }
+}
+
+// The number of cubes of each color in one draw from the bag
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Draw {
+    red: u32,
+    green: u32,
+    blue: u32,
+}
+
+#[derive(Debug)]
+pub struct Game {
+    id: u32,
+    draws: Vec<Draw>,
+}
+
+fn parse_draw(draw: &str) -> Result<Draw, ParseError> {
+    let mut counts = Draw::default();
+    for count_color in draw.split(',') {
+        let (count, color) = split_once(count_color.trim(), " ")?;
+        let count: u32 = parse_number(count)?;
+        match color {
+            "red" => counts.red += count,
+            "green" => counts.green += count,
+            "blue" => counts.blue += count,
+            _ => return Err(ParseError::unknown("color", color)),
+        }
+    }
+    Ok(counts)
+}
+
+fn parse_game(line: &str) -> Result<Game, ParseError> {
+    let (game, draws) = split_once(line, ":")?;
+    let id = parse_number(strip_prefix(game, "Game ")?)?;
+    let draws = draws.split(';').map(parse_draw).collect::<Result<_, _>>()?;
+    Ok(Game { id, draws })
+}
+
+pub fn parse(input: &str) -> Result<Vec<Game>, ParseError> {
+    input.lines().map(parse_game).collect()
+}
@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::run(&[day_2::SOLVER_A, day_2::SOLVER_B]);
+}
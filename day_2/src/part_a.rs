@@ -0,0 +1,28 @@
+use crate::{Draw, Game};
+
+const MAX_RED: u32 = 12;
+const MAX_GREEN: u32 = 13;
+const MAX_BLUE: u32 = 14;
+
+fn is_draw_possible(draw: &Draw) -> bool {
+    draw.red <= MAX_RED && draw.green <= MAX_GREEN && draw.blue <= MAX_BLUE
+}
+
+fn are_all_draws_possible(draws: &[Draw]) -> bool {
+    for draw in draws {
+        if !is_draw_possible(draw) {
+            return false;
+        }
+    }
+    true
+}
+
+pub fn solve(games: &[Game]) -> u32 {
+    let mut sum = 0;
+    for game in games {
+        if are_all_draws_possible(&game.draws) {
+            sum += game.id;
+        }
+    }
+    sum
+}
@@ -0,0 +1,23 @@
+use crate::{Draw, Game};
+
+fn max_red_green_blue(draws: &[Draw]) -> (u32, u32, u32) {
+    let mut max_red: u32 = 0;
+    let mut max_green: u32 = 0;
+    let mut max_blue: u32 = 0;
+    for draw in draws {
+        max_red = max_red.max(draw.red);
+        max_green = max_green.max(draw.green);
+        max_blue = max_blue.max(draw.blue);
+    }
+    (max_red, max_green, max_blue)
+}
+
+pub fn solve(games: &[Game]) -> u32 {
+    let mut sum = 0;
+    for game in games {
+        let (max_red, max_green, max_blue) = max_red_green_blue(&game.draws);
+        let power = max_red * max_green * max_blue;
+        sum += power;
+    }
+    sum
+}
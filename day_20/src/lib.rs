@@ -1,14 +1,47 @@
+use aoc_common::{
+    Animation, Answer, Grid, Paint, ParseError, Picture, Rgb, Solution, Solver, solver, split_once,
+    trace_event,
+};
 use std::collections::{HashMap, VecDeque};
-use std::fs::read_to_string;
+use std::fmt;
 
-#[derive(Debug)]
+pub mod part_a;
+// Not solved yet, see part_b.rs
+pub mod part_b;
+
+pub const SOLVER_A: Solver = solver! {
+    day: 20,
+    part: 'a',
+    description: "Product of low and high pulse counts",
+    solution: Day20,
+};
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Network;
+
+    fn parse(input: &str) -> Result<Network, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(input: &Network) -> Option<Answer> {
+        Some(part_a::solve(input).into())
+    }
+
+    fn animate(input: &Network, part: char, animation: &mut Animation) -> Option<Answer> {
+        (part == 'a').then(|| part_a::animate(input, animation).into())
+    }
+}
+
+#[derive(Clone, Debug)]
 enum ModuleType {
     Broadcaster,
     FlipFlop,
     Conjunction,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 struct Module {
     name: String,
     module_type: ModuleType,
@@ -18,36 +51,35 @@ struct Module {
 }
 
 impl Module {
-    fn from_line(line: &str) -> Self {
-        let source_and_dest: Vec<&str> = line.split("->").collect();
-        assert!(source_and_dest.len() == 2);
-        let source = source_and_dest[0].trim();
-        let dest = source_and_dest[1].trim();
+    fn from_line(line: &str) -> Result<Self, ParseError> {
+        let (source, dest) = split_once(line, "->")?;
+        let source = source.trim();
+        let dest = dest.trim();
         let (name, module_type) = if source == "broadcaster" {
             (source.to_string(), ModuleType::Broadcaster)
         } else {
-            let first_char = source.chars().nth(0).unwrap();
+            let first_char = source.chars().nth(0).unwrap_or(' ');
             let name = source.chars().skip(1).collect();
             match first_char {
                 '%' => (name, ModuleType::FlipFlop),
                 '&' => (name, ModuleType::Conjunction),
-                _ => panic!("Unknown module type"),
+                _ => return Err(ParseError::unknown("module type", source)),
             }
         };
         let dest_modules: Vec<String> = dest.split(',').map(|s| s.trim().to_string()).collect();
-        Module {
+        Ok(Module {
             name,
             module_type,
             dest_modules,
             dest_value: PulseValue::Low, // Initial value
-            src_values: HashMap::new(),  // Filled in later in Network::from_file
-        }
+            src_values: HashMap::new(),  // Filled in later in Network::from_input
+        })
     }
 
     fn process_pulse(&mut self, pulse: &Pulse) -> PulseValue {
         assert!(pulse.value != PulseValue::None);
         // Empty string means in pulse src_module from button
-        if pulse.src_module != "" {
+        if !pulse.src_module.is_empty() {
             *self.src_values.get_mut(&pulse.src_module).unwrap() = pulse.value;
         }
         let out_pulse_value = match self.module_type {
@@ -79,17 +111,16 @@ impl Module {
     }
 }
 
-#[derive(Debug)]
-struct Network {
+#[derive(Clone, Debug)]
+pub struct Network {
     modules: HashMap<String, Module>, // Keyed by module name
 }
 
 impl Network {
-    fn from_file(filename: &str) -> Self {
-        let lines = read_to_string(filename).unwrap();
+    fn from_input(input: &str) -> Result<Self, ParseError> {
         let mut modules: HashMap<String, Module> = HashMap::new();
-        for line in lines.lines() {
-            let module = Module::from_line(line);
+        for line in input.lines() {
+            let module = Module::from_line(line)?;
             modules.insert(module.name.clone(), module);
         }
         let mut connections: Vec<(String, String)> = Vec::new();
@@ -105,10 +136,11 @@ impl Network {
                     .insert(src_module_name, PulseValue::Low); // Initial value
             }
         }
-        Network { modules }
+        Ok(Network { modules })
     }
 
-    fn push_button(&mut self) -> (usize, usize) {
+    // Calls on_pulse with every pulse that is sent.
+    fn push_button(&mut self, on_pulse: &mut dyn FnMut(&Pulse)) -> (usize, usize) {
         let mut low_pulse_count = 0;
         let mut high_pulse_count = 0;
         let mut pulse_queue: VecDeque<Pulse> = VecDeque::new();
@@ -119,11 +151,24 @@ impl Network {
         };
         pulse_queue.push_back(button_pulse);
         while let Some(pulse) = pulse_queue.pop_front() {
+            trace_event!(
+                Trace,
+                "day20::pulse",
+                "pulse_sent",
+                src = if pulse.src_module.is_empty() {
+                    "button"
+                } else {
+                    &pulse.src_module
+                },
+                value = pulse.value,
+                dest = pulse.dest_module,
+            );
             match pulse.value {
                 PulseValue::Low => low_pulse_count += 1,
                 PulseValue::High => high_pulse_count += 1,
                 PulseValue::None => panic!("Pulse value cannot be None"),
             };
+            on_pulse(&pulse);
             // Dest module may not exist; second example contains an "untyped module named output"
             if !self.modules.contains_key(&pulse.dest_module) {
                 continue;
@@ -140,31 +185,66 @@ impl Network {
                     dest_module: next_dest_module_name.clone(),
                 };
                 pulse_queue.push_back(next_pulse);
-                // println!(
-                //     "{} -{}-> {}",
-                //     dest_module.name,
-                //     match out_pulse_value {
-                //         PulseValue::None => "none",
-                //         PulseValue::High => "high",
-                //         PulseValue::Low => "low",
-                //     },
-                //     next_dest_module_name
-                // );
             }
         }
         (low_pulse_count, high_pulse_count)
     }
 
-    fn push_button_n_times(&mut self, times: usize) -> (usize, usize) {
+    // Calls on_push with the network after every push of the button.
+    fn push_button_n_times(
+        &mut self,
+        times: usize,
+        on_push: &mut dyn FnMut(&Network),
+    ) -> (usize, usize) {
         let mut total_low_pulses = 0;
         let mut total_high_pulses = 0;
-        for _ in 0..times {
-            let (low_pulses, high_pulses) = self.push_button();
+        for push in 1..=times {
+            let (low_pulses, high_pulses) = self.push_button(&mut |_| ());
+            trace_event!(
+                Debug,
+                "day20::button",
+                "button_pushed",
+                push = push,
+                low_pulses = low_pulses,
+                high_pulses = high_pulses,
+            );
             total_low_pulses += low_pulses;
             total_high_pulses += high_pulses;
+            on_push(self);
         }
         (total_low_pulses, total_high_pulses)
     }
+
+    // The state of each module, in order of name, laid out in a square.
+    fn picture(&self) -> Picture {
+        let mut names: Vec<&String> = self.modules.keys().collect();
+        names.sort();
+        let width = (1..).find(|width| width * width >= names.len()).unwrap();
+        let height = names.len().div_ceil(width).max(1);
+        let mut cells = Grid::new(width, height, Paint::Kind("none"));
+        for (index, name) in names.into_iter().enumerate() {
+            let module = &self.modules[name];
+            let high = module.dest_value == PulseValue::High;
+            cells[(index % width, index / width)] = Paint::Kind(match module.module_type {
+                ModuleType::Broadcaster => "broadcaster",
+                ModuleType::FlipFlop if high => "flip-flop on",
+                ModuleType::FlipFlop => "flip-flop off",
+                ModuleType::Conjunction if high => "conjunction high",
+                ModuleType::Conjunction => "conjunction low",
+            });
+        }
+        Picture {
+            cells,
+            palette: vec![
+                ("broadcaster", Rgb(0xff, 0xff, 0xff)),
+                ("flip-flop on", Rgb(0x40, 0xd0, 0x40)),
+                ("flip-flop off", Rgb(0x10, 0x40, 0x10)),
+                ("conjunction high", Rgb(0xff, 0x90, 0x20)),
+                ("conjunction low", Rgb(0x50, 0x28, 0x08)),
+                ("none", Rgb(0, 0, 0)),
+            ],
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -174,6 +254,16 @@ enum PulseValue {
     Low,
 }
 
+impl fmt::Display for PulseValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            PulseValue::None => "none",
+            PulseValue::High => "high",
+            PulseValue::Low => "low",
+        })
+    }
+}
+
 impl PulseValue {
     fn opposite(&self) -> PulseValue {
         match self {
@@ -191,10 +281,6 @@ struct Pulse {
     dest_module: String,
 }
 
-fn main() {
-    let mut network = Network::from_file("puzzle_input");
-    let (low_pulse_count, high_pulse_count) = network.push_button_n_times(1000);
-    // println!("Number of low pulses sent: {}", low_pulse_count);
-    // println!("Number of high pulses sent: {}", high_pulse_count);
-    println!("Product: {}", low_pulse_count * high_pulse_count);
+pub fn parse(input: &str) -> Result<Network, ParseError> {
+    Network::from_input(input)
 }
@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::run(&[day_20::SOLVER_A]);
+}
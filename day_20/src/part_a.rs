@@ -0,0 +1,24 @@
+use crate::Network;
+use aoc_common::{Animation, trace_event};
+
+pub fn solve(network: &Network) -> usize {
+    let mut network = network.clone();
+    let (low_pulse_count, high_pulse_count) = network.push_button_n_times(1000, &mut |_| ());
+    trace_event!(
+        Info,
+        "day20",
+        "pulses_counted",
+        low_pulses = low_pulse_count,
+        high_pulses = high_pulse_count,
+    );
+    low_pulse_count * high_pulse_count
+}
+
+// The state of the modules after each push of the button
+pub fn animate(network: &Network, animation: &mut Animation) -> usize {
+    let mut network = network.clone();
+    animation.frame(|| network.picture());
+    let (low_pulse_count, high_pulse_count) =
+        network.push_button_n_times(1000, &mut |network| animation.frame(|| network.picture()));
+    low_pulse_count * high_pulse_count
+}
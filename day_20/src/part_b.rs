@@ -0,0 +1,20 @@
+use crate::{Network, PulseValue};
+
+// The number of pushes of the button until a single low pulse is sent to the rx module, trying at
+// most max_pushes. Pushing the button one push after the other, as this does, takes far too long for
+// the puzzle input, so this is not a solution yet, and part b is not registered with the runner.
+pub fn brute_force_solve(network: &Network, max_pushes: usize) -> Option<usize> {
+    let mut network = network.clone();
+    for push in 1..=max_pushes {
+        let mut low_pulses_to_rx = 0;
+        network.push_button(&mut |pulse| {
+            if pulse.dest_module == "rx" && pulse.value == PulseValue::Low {
+                low_pulses_to_rx += 1;
+            }
+        });
+        if low_pulses_to_rx == 1 {
+            return Some(push);
+        }
+    }
+    None
+}
@@ -0,0 +1,41 @@
+use aoc_common::{Animation, Answer, Grid, ParseError, Picture, Solution, Solver, solver};
+
+pub mod part_a;
+
+pub const SOLVER_A: Solver = solver! {
+    day: 21,
+    part: 'a',
+    description: "Number of reachable cells after 64 steps",
+    solution: Day21,
+};
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = (Map, Map);
+
+    fn parse(input: &str) -> Result<(Map, Map), ParseError> {
+        parse(input)
+    }
+
+    fn part_a(input: &(Map, Map)) -> Option<Answer> {
+        Some(part_a::solve(input).into())
+    }
+
+    fn render(input: &(Map, Map), part: char) -> Option<Picture> {
+        (part == 'a').then(|| part_a::render(input))
+    }
+
+    fn animate(input: &(Map, Map), part: char, animation: &mut Animation) -> Option<Answer> {
+        (part == 'a').then(|| part_a::animate(input, animation).into())
+    }
+}
+
+// Depending on the context, true means "there is a rock" or "the cell is reachable in N steps".
+pub type Map = Grid<bool>;
+
+pub fn parse(input: &str) -> Result<(Map, Map), ParseError> {
+    let rock_map = Grid::parse(input, |c| c == '#')?;
+    let reachable_map = Grid::parse(input, |c| c == 'S')?;
+    Ok((rock_map, reachable_map))
+}
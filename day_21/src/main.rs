@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::run(&[day_21::SOLVER_A]);
+}
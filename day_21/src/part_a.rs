@@ -0,0 +1,78 @@
+use crate::Map;
+use aoc_common::{Animation, Grid, Paint, Picture, Rgb};
+
+const NR_STEPS: usize = 64;
+
+pub fn solve((rock_map, reachable_map): &(Map, Map)) -> usize {
+    let (reachable_map, _) = walk(rock_map, reachable_map, &mut |_, _| ());
+    reachable_map.cells().filter(|&&cell| cell).count()
+}
+
+// The garden plots reachable in exactly 64 steps, and those reachable in an odd number of steps
+// up to 64, which alternate with them like a checkerboard
+pub fn render((rock_map, reachable_map): &(Map, Map)) -> Picture {
+    let (reachable_map, previous_reachable_map) = walk(rock_map, reachable_map, &mut |_, _| ());
+    garden_picture(rock_map, &reachable_map, &previous_reachable_map)
+}
+
+// The garden plots reachable after each step
+pub fn animate((rock_map, reachable_map): &(Map, Map), animation: &mut Animation) -> usize {
+    let no_steps = Grid::new(rock_map.width(), rock_map.height(), false);
+    animation.frame(|| garden_picture(rock_map, reachable_map, &no_steps));
+    let (reachable_map, _) = walk(rock_map, reachable_map, &mut |reachable, previous| {
+        animation.frame(|| garden_picture(rock_map, reachable, previous))
+    });
+    reachable_map.cells().filter(|&&cell| cell).count()
+}
+
+// Take all the steps, calling on_step with the cells reachable after each step and after the step
+// before it. Returns the cells reachable after the last step and after the step before it.
+fn walk(rock_map: &Map, reachable_map: &Map, on_step: &mut dyn FnMut(&Map, &Map)) -> (Map, Map) {
+    let mut previous_reachable_map = Grid::new(rock_map.width(), rock_map.height(), false);
+    let mut reachable_map = reachable_map.clone();
+    for _step in 1..=NR_STEPS {
+        let next_reachable_map = next_reachable(&reachable_map, rock_map);
+        previous_reachable_map = reachable_map;
+        reachable_map = next_reachable_map;
+        on_step(&reachable_map, &previous_reachable_map);
+    }
+    (reachable_map, previous_reachable_map)
+}
+
+fn garden_picture(rock_map: &Map, reachable_map: &Map, previous_reachable_map: &Map) -> Picture {
+    let cells = rock_map.positions().map(|pos| {
+        if rock_map[pos] {
+            Paint::Kind("rock")
+        } else if reachable_map[pos] {
+            Paint::Kind("even")
+        } else if previous_reachable_map[pos] {
+            Paint::Kind("odd")
+        } else {
+            Paint::Kind("garden")
+        }
+    });
+    Picture {
+        cells: Grid::from_cells(rock_map.width(), rock_map.height(), cells.collect()),
+        palette: vec![
+            ("rock", Rgb(0x50, 0x50, 0x50)),
+            ("even", Rgb(0x30, 0xb0, 0x40)),
+            ("odd", Rgb(0xa0, 0xe0, 0x90)),
+            ("garden", Rgb(0xf0, 0xf0, 0xd0)),
+        ],
+    }
+}
+
+fn next_reachable(current_reachable: &Map, rock_map: &Map) -> Map {
+    let mut next_reachable =
+        Grid::new(current_reachable.width(), current_reachable.height(), false);
+    for (x, y) in current_reachable.positions() {
+        if current_reachable[(x, y)] {
+            for pos in current_reachable.neighbours_4(x, y) {
+                if !rock_map[pos] {
+                    next_reachable[pos] = true;
+                }
+            }
+        }
+    }
+    next_reachable
+}
@@ -0,0 +1,50 @@
+use aoc_common::{Answer, Grid, ParseError, Solution, Solver, solver};
+
+pub mod part_a;
+pub mod part_b;
+
+pub const SOLVER_A: Solver = solver! {
+    day: 3,
+    part: 'a',
+    description: "Sum of part numbers",
+    solution: Day3,
+};
+
+pub const SOLVER_B: Solver = solver! {
+    day: 3,
+    part: 'b',
+    description: "Sum of gear ratios",
+    solution: Day3,
+};
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Schematic;
+
+    fn parse(input: &str) -> Result<Schematic, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(input: &Schematic) -> Option<Answer> {
+        Some(part_a::solve(input).into())
+    }
+
+    fn part_b(input: &Schematic) -> Option<Answer> {
+        Some(part_b::solve(input).into())
+    }
+}
+
+pub type Schematic = Grid<char>;
+pub type Span = (usize, usize, usize); // row_index, start_col_index, end_col_index
+
+pub fn parse(input: &str) -> Result<Schematic, ParseError> {
+    Grid::parse(input, |c| c)
+}
+
+fn number_at_span(schematic: &Schematic, span: Span) -> usize {
+    let (row_index, start_col_index, end_col_index) = span;
+    let row = schematic.row(row_index);
+    let number_str: String = row[start_col_index..=end_col_index].iter().collect();
+    number_str.parse().unwrap()
+}
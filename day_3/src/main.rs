@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::run(&[day_3::SOLVER_A, day_3::SOLVER_B]);
+}
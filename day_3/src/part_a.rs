@@ -0,0 +1,60 @@
+use crate::{Schematic, Span, number_at_span};
+
+fn next_number_start(row: &[char], start_col_index: usize) -> Option<usize> {
+    (start_col_index..row.len()).find(|&col_index| row[col_index].is_ascii_digit())
+}
+
+fn number_end(row: &[char], start_col_index: usize) -> usize {
+    match (start_col_index + 1..row.len()).find(|&col_index| !row[col_index].is_ascii_digit()) {
+        Some(col_index) => col_index - 1,
+        None => row.len() - 1,
+    }
+}
+
+fn extract_number_spans_from_row(row_index: usize, row: &[char]) -> Vec<Span> {
+    let mut positions = Vec::new();
+    let mut col_index = 0;
+    while let Some(start_col_index) = next_number_start(row, col_index) {
+        let end_col_index = number_end(row, start_col_index);
+        positions.push((row_index, start_col_index, end_col_index));
+        col_index = end_col_index + 1;
+    }
+    positions
+}
+
+fn is_adjacent_to_symbol(schematic: &Schematic, span: Span) -> bool {
+    let (row_index, start_col_index, end_col_index) = span;
+    let in_number = |col_index, neighbour_row_index| {
+        neighbour_row_index == row_index && (start_col_index..=end_col_index).contains(&col_index)
+    };
+    (start_col_index..=end_col_index).any(|col_index| {
+        schematic
+            .neighbours_8(col_index, row_index)
+            .any(|(x, y)| !in_number(x, y) && schematic[(x, y)] != '.')
+    })
+}
+
+fn sum_of_part_numbers_in_row(schematic: &Schematic, row_index: usize) -> usize {
+    let mut sum = 0;
+    let row = schematic.row(row_index);
+    let spans = extract_number_spans_from_row(row_index, row);
+    for span in spans {
+        if is_adjacent_to_symbol(schematic, span) {
+            let part_nr = number_at_span(schematic, span);
+            sum += part_nr;
+        }
+    }
+    sum
+}
+
+fn sum_of_part_numbers_in_schematic(schematic: &Schematic) -> usize {
+    let mut sum = 0;
+    for row_index in 0..schematic.height() {
+        sum += sum_of_part_numbers_in_row(schematic, row_index);
+    }
+    sum
+}
+
+pub fn solve(schematic: &Schematic) -> usize {
+    sum_of_part_numbers_in_schematic(schematic)
+}
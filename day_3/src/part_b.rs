@@ -0,0 +1,53 @@
+use crate::{Schematic, Span, number_at_span};
+use std::collections::HashSet;
+
+pub fn solve(schematic: &Schematic) -> usize {
+    sum_of_gear_ratios(schematic)
+}
+
+fn sum_of_gear_ratios(schematic: &Schematic) -> usize {
+    let mut sum = 0;
+    for (col_index, row_index) in schematic.positions() {
+        if schematic[(col_index, row_index)] == '*' {
+            let numbers = numbers_around_potential_gear(schematic, row_index, col_index);
+            if numbers.len() == 2 {
+                let gear_ratio = numbers[0] * numbers[1];
+                sum += gear_ratio;
+            }
+        }
+    }
+    sum
+}
+
+fn numbers_around_potential_gear(
+    schematic: &Schematic,
+    row_index: usize,
+    col_index: usize,
+) -> Vec<usize> {
+    let mut number_spans = HashSet::new();
+    for (col_index, row_index) in schematic.neighbours_8(col_index, row_index) {
+        if schematic[(col_index, row_index)].is_ascii_digit() {
+            let number_span = number_span_for_digit(schematic, row_index, col_index);
+            number_spans.insert(number_span);
+        }
+    }
+    let mut numbers = Vec::new();
+    for number_span in number_spans {
+        let number = number_at_span(schematic, number_span);
+        numbers.push(number);
+    }
+    numbers
+}
+
+fn number_span_for_digit(schematic: &Schematic, row_index: usize, col_index: usize) -> Span {
+    let row = schematic.row(row_index);
+    let mut start_col_index = col_index;
+    while start_col_index > 0 && row[start_col_index - 1].is_ascii_digit() {
+        start_col_index -= 1;
+    }
+    let mut end_col_index = col_index;
+    while end_col_index + 1 < row.len() && row[end_col_index + 1].is_ascii_digit() {
+        end_col_index += 1;
+    }
+    (row_index, start_col_index, end_col_index)
+}
@@ -0,0 +1,81 @@
+use aoc_common::{
+    Answer, ParseError, Solution, Solver, parse_number, solver, split_once, strip_prefix,
+};
+use std::collections::HashSet;
+
+pub mod part_a;
+pub mod part_b;
+
+pub const SOLVER_A: Solver = solver! {
+    day: 4,
+    part: 'a',
+    description: "Sum of all game scores",
+    solution: Day4,
+};
+
+pub const SOLVER_B: Solver = solver! {
+    day: 4,
+    part: 'b',
+    description: "Total cards",
+    solution: Day4,
+};
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<Card>;
+
+    fn parse(input: &str) -> Result<Vec<Card>, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(input: &Vec<Card>) -> Option<Answer> {
+        Some(part_a::solve(input).into())
+    }
+
+    fn part_b(input: &Vec<Card>) -> Option<Answer> {
+        Some(part_b::solve(input).into())
+    }
+}
+
+pub struct Card {
+    id: usize,
+    winning_numbers: HashSet<usize>,
+    draw_numbers: HashSet<usize>,
+}
+
+impl Card {
+    // How many of the drawn numbers are winning numbers
+    fn winning_numbers_count(&self) -> usize {
+        let mut winning_numbers_count: usize = 0;
+        for number in &self.draw_numbers {
+            if self.winning_numbers.contains(number) {
+                winning_numbers_count += 1;
+            }
+        }
+        winning_numbers_count
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Card>, ParseError> {
+    input.lines().map(read_card).collect()
+}
+
+fn read_card(line: &str) -> Result<Card, ParseError> {
+    let (intro, numbers) = split_once(line.trim(), ":")?;
+    let id = parse_number(strip_prefix(intro, "Card ")?)?;
+    let (winning_numbers, draw_numbers) = split_once(numbers.trim(), "|")?;
+    let winning_numbers = winning_numbers
+        .split_whitespace()
+        .map(parse_number)
+        .collect::<Result<HashSet<usize>, _>>()?;
+    let draw_numbers = draw_numbers
+        .split_whitespace()
+        .map(parse_number)
+        .collect::<Result<HashSet<usize>, _>>()?;
+    Ok(Card {
+        id,
+        winning_numbers,
+        draw_numbers,
+    })
+}
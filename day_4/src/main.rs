@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::run(&[day_4::SOLVER_A, day_4::SOLVER_B]);
+}
@@ -0,0 +1,14 @@
+use crate::Card;
+
+pub fn solve(cards: &[Card]) -> usize {
+    cards.iter().map(game_score).sum()
+}
+
+fn game_score(card: &Card) -> usize {
+    let winning_numbers_count = card.winning_numbers_count();
+    if winning_numbers_count > 0 {
+        1 << (winning_numbers_count - 1)
+    } else {
+        0
+    }
+}
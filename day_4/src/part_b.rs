@@ -0,0 +1,41 @@
+use crate::Card;
+use std::collections::BTreeMap;
+
+#[derive(Clone)]
+struct CardCount {
+    score: usize,
+    count: usize,
+}
+
+type Cards = BTreeMap<usize, CardCount>; // key is card id
+
+pub fn solve(cards: &[Card]) -> usize {
+    let mut cards = read_original_cards(cards);
+    collect_won_cards(&mut cards);
+    cards.values().map(|c| c.count).sum::<usize>()
+}
+
+fn read_original_cards(cards: &[Card]) -> Cards {
+    let mut counts: Cards = BTreeMap::new();
+    for card in cards {
+        let score = card.winning_numbers_count();
+        counts.insert(card.id, CardCount { score, count: 1 });
+    }
+    counts
+}
+
+fn collect_won_cards(cards: &mut Cards) {
+    let total_nr_cards = cards.len();
+    let ids: Vec<usize> = cards.keys().cloned().collect();
+    for id in ids {
+        let score = cards.get(&id).unwrap().score;
+        if score > 0 {
+            let nr_won_cards = cards.get(&id).unwrap().count;
+            let first_won_car_id = total_nr_cards.min(id + 1);
+            let last_won_card_id = total_nr_cards.min(first_won_car_id + score - 1);
+            for won_card_id in first_won_car_id..=last_won_card_id {
+                cards.get_mut(&won_card_id).unwrap().count += nr_won_cards;
+            }
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use aoc_common::{
+    Answer, ParseError, Solution, Solver, blocks, parse_number, solver, strip_prefix,
+};
+
+pub mod part_a;
+pub mod part_b;
+
+pub const SOLVER_A: Solver = solver! {
+    day: 5,
+    part: 'a',
+    description: "Closest location",
+    solution: Day5,
+};
+
+pub const SOLVER_B: Solver = solver! {
+    day: 5,
+    part: 'b',
+    description: "Closest location",
+    solution: Day5,
+};
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Almanac;
+
+    fn parse(input: &str) -> Result<Almanac, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(input: &Almanac) -> Option<Answer> {
+        Some(part_a::solve(input).into())
+    }
+
+    fn part_b(input: &Almanac) -> Option<Answer> {
+        Some(part_b::solve(input).into())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct MapItem {
+    dst_range_start: i64,
+    src_range_start: i64,
+    range_len: i64,
+}
+
+type Map = Vec<MapItem>;
+
+type MapSeq = Vec<Map>;
+
+pub struct Almanac {
+    // Part a reads these as seeds, part b as pairs of the start and length of a range of seeds
+    seeds: Vec<i64>,
+    map_seq: MapSeq,
+}
+
+pub fn parse(input: &str) -> Result<Almanac, ParseError> {
+    let blocks = blocks(input);
+    let seeds_line = blocks
+        .first()
+        .and_then(|block| block.first())
+        .ok_or_else(|| ParseError::missing("seeds", &input[..0]))?;
+    let seeds = read_seeds(seeds_line)?;
+    let map_seq = read_map_seq(&blocks[1..])?;
+    Ok(Almanac { seeds, map_seq })
+}
+
+fn read_seeds(line: &str) -> Result<Vec<i64>, ParseError> {
+    let seeds = strip_prefix(line, "seeds: ")?
+        .split_whitespace()
+        .map(parse_number)
+        .collect::<Result<Vec<i64>, _>>()?;
+    if seeds.is_empty() {
+        return Err(ParseError::missing("seed", line));
+    }
+    Ok(seeds)
+}
+
+fn read_map(block: &[&str]) -> Result<Map, ParseError> {
+    let mut map: Map = Map::new();
+    // skip foo-to-bar map: line
+    for line in &block[1..] {
+        let numbers = line
+            .split_whitespace()
+            .map(parse_number)
+            .collect::<Result<Vec<i64>, _>>()?;
+        let [dst_range_start, src_range_start, range_len] = numbers[..] else {
+            return Err(ParseError::expected("three numbers", line));
+        };
+        let map_item = MapItem {
+            dst_range_start,
+            src_range_start,
+            range_len,
+        };
+        map.push(map_item);
+    }
+    Ok(map)
+}
+
+fn read_map_seq(blocks: &[Vec<&str>]) -> Result<MapSeq, ParseError> {
+    let mut map_seq = MapSeq::new();
+    for block in blocks {
+        let map = read_map(block)?;
+        map_seq.push(map);
+    }
+    Ok(map_seq)
+}
@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::run(&[day_5::SOLVER_A, day_5::SOLVER_B]);
+}
@@ -0,0 +1,28 @@
+use crate::{Almanac, Map, MapSeq};
+
+pub fn solve(almanac: &Almanac) -> i64 {
+    let locations = almanac
+        .seeds
+        .iter()
+        .map(|s| apply_map_seq(&almanac.map_seq, *s))
+        .collect::<Vec<i64>>();
+    *locations.iter().min().unwrap()
+}
+
+fn apply_map_seq(map_seq: &MapSeq, nr: i64) -> i64 {
+    let mut current = nr;
+    for map in map_seq {
+        current = apply_map(map, current);
+    }
+    current
+}
+
+fn apply_map(map: &Map, nr: i64) -> i64 {
+    for item in map {
+        if nr >= item.src_range_start && nr < item.src_range_start + item.range_len {
+            let offset = nr - item.src_range_start;
+            return item.dst_range_start + offset;
+        }
+    }
+    nr
+}
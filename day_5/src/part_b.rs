@@ -1,5 +1,5 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use crate::{Almanac, Map, MapItem, MapSeq};
+use aoc_common::assume;
 
 #[derive(Debug, Clone)]
 struct Range {
@@ -9,44 +9,26 @@ struct Range {
 
 type RangeSeq = Vec<Range>;
 
-#[derive(Debug, Clone)]
-struct MapItem {
-    dst_range_start: i64,
-    src_range_start: i64,
-    range_len: i64,
-}
-
-type Map = Vec<MapItem>;
-
-type MapSeq = Vec<Map>;
-
 #[derive(Debug, Clone)]
 struct IntermediateMapResult {
     mapped_ranges: RangeSeq,
     unmapped_ranges: RangeSeq,
 }
 
-fn main() {
-    let file = File::open("puzzle_input").unwrap();
-    let mut reader = BufReader::new(file);
-    let seed_ranges = read_seed_ranges(&mut reader);
-    let nr_maps = 7;
-    let map_seq = read_map_seq(&mut reader, nr_maps);
-    let mut result_ranges = apply_map_seq_to_range_seq(&map_seq, &seed_ranges);
-    assert!(result_ranges.len() > 0);
-    result_ranges.sort_by(|a, b| a.start.cmp(&b.start));
-    let closest_location = result_ranges[0].start;
-    println!("Closest location: {}", closest_location);
+pub fn solve(almanac: &Almanac) -> i64 {
+    let seed_ranges = seed_ranges(&almanac.seeds);
+    let mut result_ranges = apply_map_seq_to_range_seq(&almanac.map_seq, &seed_ranges);
+    assert!(!result_ranges.is_empty());
+    result_ranges.sort_by_key(|a| a.start);
+    result_ranges[0].start
 }
 
-fn read_seed_ranges(reader: &mut BufReader<File>) -> RangeSeq {
+fn seed_ranges(numbers: &[i64]) -> RangeSeq {
     let mut seed_ranges = RangeSeq::new();
-    let line = next_line(reader).unwrap();
-    let numbers = &line[7..]
-        .split_whitespace()
-        .map(|n| n.trim().parse::<i64>().unwrap())
-        .collect::<Vec<i64>>();
-    assert!(numbers.len() % 2 == 0);
+    assume(
+        numbers.len().is_multiple_of(2),
+        "the seeds are pairs of a start and a length",
+    );
     let nr_pairs = numbers.len() / 2;
     for i in 0..nr_pairs {
         let start = numbers[2 * i];
@@ -54,53 +36,11 @@ fn read_seed_ranges(reader: &mut BufReader<File>) -> RangeSeq {
         let range = Range { start, len };
         seed_ranges.push(range);
     }
-    next_line(reader).unwrap(); // skip blank line
     seed_ranges
 }
 
-fn read_map(reader: &mut BufReader<File>) -> Map {
-    let mut map: Map = Map::new();
-    next_line(reader).unwrap(); // skip foo-to-bar map: line
-    while let Some(line) = next_line(reader) {
-        if line.is_empty() {
-            break;
-        }
-        let numbers = line
-            .split_whitespace()
-            .map(|n| n.trim().parse::<i64>().unwrap())
-            .collect::<Vec<i64>>();
-        assert!(numbers.len() == 3);
-        let map_item = MapItem {
-            dst_range_start: numbers[0],
-            src_range_start: numbers[1],
-            range_len: numbers[2],
-        };
-        map.push(map_item);
-    }
-    map
-}
-
-fn next_line(reader: &mut BufReader<File>) -> Option<String> {
-    let mut line = String::new();
-    let bytes_read = reader.read_line(&mut line).unwrap();
-    if bytes_read == 0 {
-        return None;
-    }
-    line = line.trim().to_string();
-    Some(line)
-}
-	
-fn read_map_seq(reader: &mut BufReader<File>, nr_maps: i64) -> MapSeq {
-    let mut map_seq = MapSeq::new();
-    for _ in 0..nr_maps {
-        let map = read_map(reader);
-        map_seq.push(map);
-    }
-    map_seq
-}
-
 fn apply_map_item_to_range(map_item: &MapItem, range: &Range) -> IntermediateMapResult {
-    let mut result = IntermediateMapResult{
+    let mut result = IntermediateMapResult {
         mapped_ranges: RangeSeq::new(),
         unmapped_ranges: RangeSeq::new(),
     };
@@ -132,7 +72,10 @@ fn sub_range_before_map_item(range: &Range, map_item: &MapItem) -> Option<Range>
     let before_start = range.start;
     let before_end = std::cmp::min(range_end, map_item.src_range_start - 1);
     let before_len = before_end - before_start + 1;
-    Some(Range {start: before_start, len: before_len})
+    Some(Range {
+        start: before_start,
+        len: before_len,
+    })
 }
 
 fn sub_range_overlap_map_item(range: &Range, map_item: &MapItem) -> Option<Range> {
@@ -147,7 +90,10 @@ fn sub_range_overlap_map_item(range: &Range, map_item: &MapItem) -> Option<Range
     let overlap_start = std::cmp::max(range.start, map_item.src_range_start);
     let overlap_end = std::cmp::min(range_end, map_item_end);
     let overlap_len = overlap_end - overlap_start + 1;
-    Some(Range {start: overlap_start, len: overlap_len})
+    Some(Range {
+        start: overlap_start,
+        len: overlap_len,
+    })
 }
 
 fn sub_range_after_map_item(range: &Range, map_item: &MapItem) -> Option<Range> {
@@ -159,33 +105,42 @@ fn sub_range_after_map_item(range: &Range, map_item: &MapItem) -> Option<Range>
     let after_start = std::cmp::max(range.start, map_item_end + 1);
     let after_end = range_end;
     let after_len = after_end - after_start + 1;
-    Some(Range {start: after_start, len: after_len})
+    Some(Range {
+        start: after_start,
+        len: after_len,
+    })
 }
 
 fn apply_map_to_range(map: &Map, range: &Range) -> RangeSeq {
-    let mut final_result = IntermediateMapResult{
+    let mut final_result = IntermediateMapResult {
         mapped_ranges: RangeSeq::new(),
         unmapped_ranges: vec![range.clone()],
     };
     for map_item in map {
         let mut step_result = apply_map_item_to_ranges(map_item, &final_result.unmapped_ranges);
-        final_result.mapped_ranges.append(&mut step_result.mapped_ranges);
+        final_result
+            .mapped_ranges
+            .append(&mut step_result.mapped_ranges);
         final_result.unmapped_ranges = step_result.unmapped_ranges;
     }
     // Any ranges still unmapped after applying all map items remain unchanged
-    let ranges = vec![final_result.mapped_ranges, final_result.unmapped_ranges].concat();
-    ranges
+
+    [final_result.mapped_ranges, final_result.unmapped_ranges].concat()
 }
 
 fn apply_map_item_to_ranges(map_item: &MapItem, ranges: &RangeSeq) -> IntermediateMapResult {
-    let mut final_result = IntermediateMapResult{
+    let mut final_result = IntermediateMapResult {
         mapped_ranges: RangeSeq::new(),
         unmapped_ranges: RangeSeq::new(),
     };
     for range in ranges {
         let mut step_result = apply_map_item_to_range(map_item, range);
-        final_result.mapped_ranges.append(&mut step_result.mapped_ranges);
-        final_result.unmapped_ranges.append(&mut step_result.unmapped_ranges);
+        final_result
+            .mapped_ranges
+            .append(&mut step_result.mapped_ranges);
+        final_result
+            .unmapped_ranges
+            .append(&mut step_result.unmapped_ranges);
     }
     final_result
 }
@@ -217,8 +172,8 @@ fn test_simple_subset() {
     let map = vec![map_item.clone()];
     let map_seq = vec![map.clone()];
     let range = Range {
-        start: 110, 
-        len: 30, 
+        start: 110,
+        len: 30,
     };
     let range_seq = vec![range.clone()];
     let mapped_range_seq = apply_map_seq_to_range_seq(&map_seq, &range_seq);
@@ -236,10 +191,7 @@ fn test_simple_fully_before() {
     };
     let map = vec![map_item.clone()];
     let map_seq = vec![map.clone()];
-    let range = Range {
-        start: 50, 
-        len: 30, 
-    };
+    let range = Range { start: 50, len: 30 };
     let range_seq = vec![range.clone()];
     let mapped_range_seq = apply_map_seq_to_range_seq(&map_seq, &range_seq);
     assert_eq!(mapped_range_seq.len(), 1);
@@ -257,8 +209,8 @@ fn test_simple_fully_after() {
     let map = vec![map_item.clone()];
     let map_seq = vec![map.clone()];
     let range = Range {
-        start: 260, 
-        len: 30, 
+        start: 260,
+        len: 30,
     };
     let range_seq = vec![range.clone()];
     let mapped_range_seq = apply_map_seq_to_range_seq(&map_seq, &range_seq);
@@ -276,10 +228,7 @@ fn test_simple_begin_overlap() {
     };
     let map = vec![map_item.clone()];
     let map_seq = vec![map.clone()];
-    let range = Range {
-        start: 90, 
-        len: 20, 
-    };
+    let range = Range { start: 90, len: 20 };
     let range_seq = vec![range.clone()];
     let mapped_range_seq = apply_map_seq_to_range_seq(&map_seq, &range_seq);
     assert_eq!(mapped_range_seq.len(), 2);
@@ -299,8 +248,8 @@ fn test_simple_end_overlap() {
     let map = vec![map_item.clone()];
     let map_seq = vec![map.clone()];
     let range = Range {
-        start: 140, 
-        len: 20, 
+        start: 140,
+        len: 20,
     };
     let range_seq = vec![range.clone()];
     let mapped_range_seq = apply_map_seq_to_range_seq(&map_seq, &range_seq);
@@ -320,10 +269,7 @@ fn test_simple_superset() {
     };
     let map = vec![map_item.clone()];
     let map_seq = vec![map.clone()];
-    let range = Range {
-        start: 90, 
-        len: 70, 
-    };
+    let range = Range { start: 90, len: 70 };
     let range_seq = vec![range.clone()];
     let mapped_range_seq = apply_map_seq_to_range_seq(&map_seq, &range_seq);
     assert_eq!(mapped_range_seq.len(), 3);
@@ -349,10 +295,7 @@ fn test_multi_item_superset() {
     };
     let map = vec![map_item_1.clone(), map_item_2.clone()];
     let map_seq = vec![map.clone()];
-    let range = Range {
-        start: 90, 
-        len: 70, 
-    };
+    let range = Range { start: 90, len: 70 };
     let range_seq = vec![range.clone()];
     let mapped_range_seq = apply_map_seq_to_range_seq(&map_seq, &range_seq);
     assert_eq!(mapped_range_seq.len(), 4);
@@ -386,10 +329,7 @@ fn test_multi_map_superset() {
     };
     let map_b = vec![map_item_b1.clone()];
     let map_seq = vec![map_a.clone(), map_b.clone()];
-    let range = Range {
-        start: 90, 
-        len: 70, 
-    };
+    let range = Range { start: 90, len: 70 };
     let range_seq = vec![range.clone()];
     let mapped_range_seq = apply_map_seq_to_range_seq(&map_seq, &range_seq);
     assert_eq!(mapped_range_seq.len(), 5);
@@ -403,4 +343,4 @@ fn test_multi_map_superset() {
     assert_eq!(mapped_range_seq[3].len, 5);
     assert_eq!(mapped_range_seq[4].start, 150);
     assert_eq!(mapped_range_seq[4].len, 10);
-}
\ No newline at end of file
+}
@@ -0,0 +1,151 @@
+use aoc_common::{Answer, ParseError, Solution, Solver, parse_number, solver, split_once};
+
+pub mod part_a;
+pub mod part_b;
+
+pub const SOLVER_A: Solver = solver! {
+    day: 6,
+    part: 'a',
+    description: "Product of number of ways to win",
+    solution: Day6,
+};
+
+pub const SOLVER_B: Solver = solver! {
+    day: 6,
+    part: 'b',
+    description: "Number of ways to win",
+    solution: Day6,
+};
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Races;
+
+    fn parse(input: &str) -> Result<Races, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(input: &Races) -> Option<Answer> {
+        Some(part_a::solve(input).into())
+    }
+
+    fn part_b(input: &Races) -> Option<Answer> {
+        Some(part_b::solve(input).into())
+    }
+}
+
+pub struct Races {
+    pub times: Vec<u64>,
+    pub distances: Vec<u64>,
+    // Part b reads the numbers of the races as the digits of a single race, with bad kerning
+    pub kerned: Race,
+}
+
+pub struct Race {
+    pub time: u64,
+    pub distance: u64,
+}
+
+pub fn parse(input: &str) -> Result<Races, ParseError> {
+    let mut lines = input.lines();
+    let mut next_line = |what| {
+        lines
+            .next()
+            .ok_or_else(|| ParseError::missing(what, &input[input.len()..]))
+    };
+    let times_line = next_line("times")?;
+    let distances_line = next_line("distances")?;
+    let times = read_numbers(times_line)?;
+    let distances = read_numbers(distances_line)?;
+    if times.len() != distances.len() {
+        return Err(ParseError::expected(
+            "as many distances as times",
+            distances_line,
+        ));
+    }
+    let kerned = Race {
+        time: read_kerned_number(times_line)?,
+        distance: read_kerned_number(distances_line)?,
+    };
+    Ok(Races {
+        times,
+        distances,
+        kerned,
+    })
+}
+
+fn read_numbers(line: &str) -> Result<Vec<u64>, ParseError> {
+    let (_, numbers) = split_once(line, ":")?;
+    numbers.split_whitespace().map(parse_number).collect()
+}
+
+// The numbers on the line as a single number, without the spaces between them
+fn read_kerned_number(line: &str) -> Result<u64, ParseError> {
+    let (_, numbers) = split_once(line, ":")?;
+    let digits: String = numbers.split_whitespace().collect();
+    digits
+        .parse()
+        .map_err(|_| ParseError::invalid_number(numbers.trim()))
+}
+
+fn brute_force_nr_ways_to_win(max_time: u64, min_distance: u64) -> u64 {
+    let mut count = 0;
+    for charge_time in 1..=max_time {
+        if charge_time_is_feasible(max_time, charge_time, min_distance) {
+            count += 1;
+        }
+    }
+    count
+}
+
+fn charge_time_is_feasible(max_time: u64, charge_time: u64, min_distance: u64) -> bool {
+    let distance = distance_for_charge_time(max_time, charge_time);
+    distance > min_distance
+}
+
+fn distance_for_charge_time(max_time: u64, charge_time: u64) -> u64 {
+    let speed = charge_time;
+    let remaining_time = max_time - charge_time;
+
+    speed * remaining_time
+}
+
+fn math_nr_ways_to_win(max_time: u64, min_distance: u64) -> u64 {
+    //
+    // Actual distance traveled is a quadratic function of charge time:
+    //
+    // distance = speed * time
+    //          = charge_time * (max_time - charge_time)
+    //          = charge_time * max_time - charge_time^2
+    //          = -charge_time^2 + max_time * charge_time
+    //
+    // For the record to be beaten:
+    //
+    // distance > min_distance =>
+    // -charge_time^2 + max_time * charge_time > min_distance =>
+    // charge_time^2 - max_time * charge_time + min_distance < 0
+    //
+    // The quadratic function has two roots:
+    //
+    // charge_time = (max_time +- sqrt(max_time^2 - 4 * min_distance)) / 2
+    //
+    let discriminant = max_time as f64 * max_time as f64 - 4.0 * min_distance as f64;
+    assert!(discriminant >= 0.0);
+    let sqrt_discriminant = discriminant.sqrt();
+    let root1 = (max_time as f64 - sqrt_discriminant) / 2.0;
+    let root2 = (max_time as f64 + sqrt_discriminant) / 2.0;
+    let mut min_feasible_time = root1.ceil() as u64;
+    let mut max_feasible_time = root2.floor() as u64;
+    // Explicitly check that we exceed the minimum distance at the boundaries
+    if !charge_time_is_feasible(max_time, min_feasible_time, min_distance) {
+        min_feasible_time += 1;
+    }
+    if !charge_time_is_feasible(max_time, max_feasible_time, min_distance) {
+        max_feasible_time -= 1;
+    }
+    if min_feasible_time > max_feasible_time {
+        return 0;
+    }
+    max_feasible_time - min_feasible_time + 1
+}
@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::run(&[day_6::SOLVER_A, day_6::SOLVER_B]);
+}
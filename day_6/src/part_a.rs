@@ -0,0 +1,23 @@
+use crate::{Races, brute_force_nr_ways_to_win, math_nr_ways_to_win};
+
+pub fn solve(races: &Races) -> u64 {
+    math_solve(&races.times, &races.distances)
+}
+
+pub fn brute_force_solve(times: &[u64], distances: &[u64]) -> u64 {
+    let mut product = 1u64;
+    for (time, distance) in times.iter().zip(distances.iter()) {
+        let ways = brute_force_nr_ways_to_win(*time, *distance);
+        product *= ways;
+    }
+    product
+}
+
+pub fn math_solve(times: &[u64], distances: &[u64]) -> u64 {
+    let mut product = 1u64;
+    for (time, distance) in times.iter().zip(distances.iter()) {
+        let ways = math_nr_ways_to_win(*time, *distance);
+        product *= ways;
+    }
+    product
+}
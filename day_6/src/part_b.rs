@@ -0,0 +1,13 @@
+use crate::{Races, brute_force_nr_ways_to_win, math_nr_ways_to_win};
+
+pub fn solve(races: &Races) -> u64 {
+    math_solve(races.kerned.time, races.kerned.distance)
+}
+
+pub fn brute_force_solve(time: u64, distance: u64) -> u64 {
+    brute_force_nr_ways_to_win(time, distance)
+}
+
+pub fn math_solve(time: u64, distance: u64) -> u64 {
+    math_nr_ways_to_win(time, distance)
+}
@@ -1,9 +1,11 @@
-use std::cmp::{Eq, PartialEq, Ord, PartialOrd};
 use std::fmt;
 
+// The labels of the cards, weakest first (apart from the jack in part b)
+pub const LABELS: &str = "23456789TJQKA";
+
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub struct Card {
-    label: char,
+    pub label: char,
 }
 
 impl fmt::Display for Card {
@@ -15,11 +17,14 @@ impl fmt::Display for Card {
 
 impl From<u8> for Card {
     fn from(value: u8) -> Self {
-        Card { label: value as char }
+        Card {
+            label: value as char,
+        }
     }
 }
 
-fn card_value(card: &Card) -> u8 {
+// The value of the card when the jack is worth jack_value, which differs between the parts
+pub fn card_value(card: &Card, jack_value: u8) -> u8 {
     match card.label {
         '2' => 2,
         '3' => 3,
@@ -30,22 +35,10 @@ fn card_value(card: &Card) -> u8 {
         '8' => 8,
         '9' => 9,
         'T' => 10,
-        'J' => 11,
+        'J' => jack_value,
         'Q' => 12,
         'K' => 13,
         'A' => 14,
         _ => panic!("Invalid card: {}", card.label),
     }
 }
-
-impl Ord for Card {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        card_value(self).cmp(&card_value(other))
-    }
-}
-
-impl PartialOrd for Card {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.cmp(other))
-    }
-}
@@ -1,9 +1,8 @@
-use std::cmp::{Eq, Ord, Ordering, PartialEq, PartialOrd};
+use crate::card::Card;
+use std::cmp::{Eq, Ord, PartialEq, PartialOrd};
 use std::collections::HashMap;
 use std::fmt;
 use std::str::FromStr;
-use crate::card::Card;
-
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 pub enum Rank {
@@ -18,8 +17,7 @@ pub enum Rank {
 
 #[derive(Debug, Eq, PartialEq, Clone, Copy)]
 pub struct Hand {
-    cards: [Card; 5],
-    rank: Rank,
+    pub cards: [Card; 5],
 }
 
 impl fmt::Display for Hand {
@@ -27,7 +25,6 @@ impl fmt::Display for Hand {
         for card in self.cards.iter() {
             write!(f, "{}", card)?;
         }
-        write!(f, " {:?}", self.rank)?;
         Ok(())
     }
 }
@@ -46,49 +43,28 @@ impl FromStr for Hand {
             .collect::<Vec<Card>>()
             .try_into()
             .unwrap();
-        let rank = rank_of_cards(&cards);
-        Ok(Hand { cards, rank })
-    }
-}
-
-impl Ord for Hand {
-    fn cmp(&self, _other: &Self) -> std::cmp::Ordering {
-        let rank_ordering = self.rank.cmp(&_other.rank);
-        if rank_ordering != Ordering::Equal {
-            return rank_ordering;
-        }
-        for (card_self, card_other) in self.cards.iter().zip(_other.cards.iter()) {
-            let card_ordering = card_self.cmp(card_other);
-            if card_ordering != Ordering::Equal {
-                return card_ordering;
-            }
-        }
-        Ordering::Equal
-    }
-}
-
-impl PartialOrd for Hand {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.cmp(other))
+        Ok(Hand { cards })
     }
 }
 
-fn rank_of_cards(cards: &[Card; 5]) -> Rank {
+// The rank of the cards, where each card is what its label says
+pub fn rank_of_cards(cards: &[Card; 5]) -> Rank {
     // Count how many of each card we have
     let mut frequencies: HashMap<Card, u8> = HashMap::new();
     for card in cards.iter() {
-        frequencies.entry(*card).and_modify(|c| *c += 1).or_insert(1);
+        frequencies
+            .entry(*card)
+            .and_modify(|c| *c += 1)
+            .or_insert(1);
     }
     // Sort by count descending (and discard card value)
-    let mut frequencies: Vec<_> = frequencies
-        .iter()
-        .map(|(_, &count)| count)
-        .collect();
+    let mut frequencies: Vec<_> = frequencies.iter().map(|(_, &count)| count).collect();
     frequencies.sort_by(|a, b| b.cmp(a));
     // Get top 2 counts
     let top1 = frequencies[0];
     let top2 = frequencies.get(1).unwrap_or(&0);
-    let rank = match (top1, top2) {
+
+    match (top1, top2) {
         (5, _) => Rank::FiveOfAKind,
         (4, _) => Rank::FourOfAKind,
         (3, 2) => Rank::FullHouse,
@@ -96,6 +72,5 @@ fn rank_of_cards(cards: &[Card; 5]) -> Rank {
         (2, 2) => Rank::TwoPair,
         (2, _) => Rank::OnePair,
         (_, _) => Rank::HighCard,
-    };
-    rank
-}
\ No newline at end of file
+    }
+}
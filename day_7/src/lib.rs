@@ -0,0 +1,78 @@
+// A bit over the top with all the types, but hey, the point is to learn Rust, not to solve the problem asap
+
+mod card;
+mod hand;
+pub mod part_a;
+pub mod part_b;
+
+use crate::hand::Hand;
+use aoc_common::{
+    Answer, ParseError, Solution, Solver, only_chars, parse_number, solver, split_once,
+};
+use std::str::FromStr;
+
+pub const SOLVER_A: Solver = solver! {
+    day: 7,
+    part: 'a',
+    description: "Winnings",
+    solution: Day7,
+};
+
+pub const SOLVER_B: Solver = solver! {
+    day: 7,
+    part: 'b',
+    description: "Winnings",
+    solution: Day7,
+};
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<Play>;
+
+    fn parse(input: &str) -> Result<Vec<Play>, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(input: &Vec<Play>) -> Option<Answer> {
+        Some(part_a::solve(input).into())
+    }
+
+    fn part_b(input: &Vec<Play>) -> Option<Answer> {
+        Some(part_b::solve(input).into())
+    }
+}
+
+pub type Play = (Hand, u64);
+
+pub fn parse(input: &str) -> Result<Vec<Play>, ParseError> {
+    // Read all plays
+    let mut plays: Vec<Play> = vec![];
+    for line in input.lines() {
+        let (hand, bid) = parse_line(line)?;
+        plays.push((hand, bid));
+    }
+    Ok(plays)
+}
+
+fn parse_line(line: &str) -> Result<(Hand, u64), ParseError> {
+    let (hand_str, bid_str) = split_once(line, " ")?;
+    only_chars(hand_str, card::LABELS, "card")?;
+    let hand = Hand::from_str(hand_str).map_err(|()| ParseError::wrong_length(5, hand_str))?;
+    let bid = parse_number(bid_str)?;
+    Ok((hand, bid))
+}
+
+// The winnings of the plays when the hands are ordered by their strength, weakest first.
+fn total_winnings<K: Ord>(plays: &[Play], strength: impl Fn(&Hand) -> K) -> u64 {
+    // Sort plays by hand strength, weakest first
+    let mut plays = plays.to_vec();
+    plays.sort_by_cached_key(|(hand, _)| strength(hand));
+    // Compute winnings
+    let mut winnings: u64 = 0;
+    for (index, play) in plays.iter().enumerate() {
+        let rank = index + 1;
+        winnings += play.1 * rank as u64;
+    }
+    winnings
+}
@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::run(&[day_7::SOLVER_A, day_7::SOLVER_B]);
+}
@@ -0,0 +1,15 @@
+use crate::Play;
+use crate::card::card_value;
+use crate::hand::{Hand, Rank, rank_of_cards};
+
+// Hands are ordered by rank first, and then by the value of their cards from the first one on
+fn strength(hand: &Hand) -> (Rank, [u8; 5]) {
+    (
+        rank_of_cards(&hand.cards),
+        hand.cards.map(|card| card_value(&card, 11)),
+    )
+}
+
+pub fn solve(plays: &[Play]) -> u64 {
+    crate::total_winnings(plays, strength)
+}
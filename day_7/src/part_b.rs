@@ -0,0 +1,51 @@
+use crate::Play;
+use crate::card::{Card, card_value};
+use crate::hand::{Hand, Rank, rank_of_cards};
+
+const JOKER: Card = Card { label: 'J' };
+
+fn all_non_joker_cards() -> Vec<Card> {
+    let labels = ['2', '3', '4', '5', '6', '7', '8', '9', 'T', 'Q', 'K', 'A'];
+    labels.iter().map(|&label| Card { label }).collect()
+}
+
+// J is a joker: it makes the best hand it can as any other card, but on its own it is the weakest
+// card
+fn strength(hand: &Hand) -> (Rank, [u8; 5]) {
+    (
+        rank_of_cards_with_jokers(&hand.cards),
+        hand.cards.map(|card| card_value(&card, 1)),
+    )
+}
+
+fn rank_of_cards_with_jokers(cards: &[Card; 5]) -> Rank {
+    // Treat JJJJJ as a special case
+    if cards.iter().all(|&c| c == JOKER) {
+        return Rank::FiveOfAKind;
+    }
+    rank_of_cards_with_maybe_jokers(cards)
+}
+
+fn rank_of_cards_with_maybe_jokers(cards: &[Card; 5]) -> Rank {
+    // Find the first joker
+    if let Some((index, _)) = cards.iter().enumerate().find(|&(_, c)| *c == JOKER) {
+        // Try all possible replacements for the joker
+        let mut best_rank = Rank::HighCard;
+        for replacement in all_non_joker_cards().iter() {
+            let mut new_cards = *cards;
+            new_cards[index] = *replacement;
+            let rank = rank_of_cards_with_maybe_jokers(&new_cards);
+            if rank > best_rank {
+                best_rank = rank;
+            }
+        }
+        best_rank
+    } else {
+        // No jokers, just rank normally
+        rank_of_cards(cards)
+    }
+}
+
+pub fn solve(plays: &[Play]) -> u64 {
+    crate::total_winnings(plays, strength)
+}
@@ -0,0 +1,114 @@
+use aoc_common::{
+    Answer, ParseError, Solution, Solver, blocks, only_chars, solver, split_once, strip_prefix,
+};
+use std::collections::{HashMap, HashSet};
+
+pub mod part_a;
+pub mod part_b;
+
+pub const SOLVER_A: Solver = solver! {
+    day: 8,
+    part: 'a',
+    description: "Steps",
+    solution: Day8,
+};
+
+pub const SOLVER_B: Solver = solver! {
+    day: 8,
+    part: 'b',
+    description: "Steps",
+    solution: Day8,
+};
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Map, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(input: &Map) -> Option<Answer> {
+        Some(part_a::solve(input).into())
+    }
+
+    fn part_b(input: &Map) -> Option<Answer> {
+        Some(part_b::solve(input).into())
+    }
+}
+
+#[derive(Debug)]
+struct Node {
+    name: String,
+    left: String,
+    right: String,
+}
+
+impl Node {
+    // The name of the next node in the direction
+    fn next(&self, direction_char: char) -> String {
+        match direction_char {
+            'L' => self.left.clone(),
+            'R' => self.right.clone(),
+            _ => panic!("Invalid direction: {direction_char}"),
+        }
+    }
+}
+
+pub struct Map {
+    directions: String,
+    network: HashMap<String, Node>,
+}
+
+pub fn parse(input: &str) -> Result<Map, ParseError> {
+    let blocks = blocks(input);
+    let directions = blocks
+        .first()
+        .and_then(|block| block.first())
+        .ok_or_else(|| ParseError::missing("directions", &input[input.len()..]))?;
+    only_chars(directions, "LR", "direction")?;
+    let network_lines = blocks
+        .get(1)
+        .ok_or_else(|| ParseError::missing("network", &input[input.len()..]))?;
+    let network = read_network(network_lines)?;
+    Ok(Map {
+        directions: directions.to_string(),
+        network,
+    })
+}
+
+// Every node that a node leads to must be in the network, so that every path can be followed.
+fn read_network(lines: &[&str]) -> Result<HashMap<String, Node>, ParseError> {
+    let nodes = lines
+        .iter()
+        .map(|line| read_node(line))
+        .collect::<Result<Vec<_>, _>>()?;
+    let names: HashSet<&str> = nodes.iter().map(|&(name, _, _)| name).collect();
+    let mut network = HashMap::new();
+    for (name, left, right) in nodes {
+        for next_name in [left, right] {
+            if !names.contains(next_name) {
+                return Err(ParseError::unknown("node", next_name));
+            }
+        }
+        let node = Node {
+            name: name.to_string(),
+            left: left.to_string(),
+            right: right.to_string(),
+        };
+        network.insert(node.name.clone(), node);
+    }
+    Ok(network)
+}
+
+// A node such as "AAA = (BBB, CCC)", as its name and the names of its left and right nodes
+fn read_node(line: &str) -> Result<(&str, &str, &str), ParseError> {
+    let (name, next_str) = split_once(line, " = ")?;
+    let next_str = strip_prefix(next_str, "(")?;
+    let next_str = next_str
+        .strip_suffix(')')
+        .ok_or_else(|| ParseError::expected(")", next_str))?;
+    let (left, right) = split_once(next_str, ", ")?;
+    Ok((name, left, right))
+}
@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::run(&[day_8::SOLVER_A, day_8::SOLVER_B]);
+}
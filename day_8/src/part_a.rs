@@ -0,0 +1,32 @@
+use crate::{Map, Node};
+use aoc_common::assume;
+use std::collections::HashMap;
+
+pub fn solve(map: &Map) -> u64 {
+    go_aaa_to_zzz(&map.network, &map.directions)
+}
+
+fn go_aaa_to_zzz(network: &HashMap<String, Node>, directions: &str) -> u64 {
+    // Only part a starts from AAA, so the parser can't require it
+    assume(network.contains_key("AAA"), "the network has a node AAA");
+    // After this many steps, some node has been visited twice at the same point in the directions,
+    // so the path goes around in a cycle without ZZZ
+    let max_steps = (network.len() * directions.len()) as u64;
+    let mut steps: u64 = 0;
+    let mut current = "AAA".to_string();
+    let mut direction_index = 0;
+    loop {
+        if current == "ZZZ" {
+            return steps;
+        }
+        assume(steps <= max_steps, "the path from AAA reaches ZZZ");
+        let node = network.get(&current).unwrap();
+        let direction_char = directions.chars().nth(direction_index).unwrap();
+        current = node.next(direction_char);
+        direction_index += 1;
+        if direction_index >= directions.len() {
+            direction_index = 0;
+        }
+        steps += 1;
+    }
+}
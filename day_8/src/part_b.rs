@@ -0,0 +1,87 @@
+use crate::{Map, Node};
+use aoc_common::assume;
+use std::collections::HashMap;
+
+pub fn solve(map: &Map) -> u128 {
+    go_all_xxa_to_all_xxz(&map.network, &map.directions)
+}
+
+// The cycle lengths fit in a u64, but their least common multiple need not.
+fn go_all_xxa_to_all_xxz(network: &HashMap<String, Node>, directions: &str) -> u128 {
+    let mut each_xxa_steps = Vec::new();
+    for start_name in all_xxa(network) {
+        let steps = go_xxa_to_xxz(network, &start_name, directions);
+        each_xxa_steps.push(steps);
+    }
+    each_xxa_steps
+        .iter()
+        .fold(1, |acc, &x| least_common_multiple(acc, u128::from(x)))
+}
+
+fn go_xxa_to_xxz(network: &HashMap<String, Node>, start_name: &str, directions: &str) -> u64 {
+    //
+    // This solution relies on the fact that the problem input has been carefully crafter so that
+    // every path from an 'XXA' node goes through a period cycle visiting the same XXZ node over
+    // and over again, using the same number of initial steps and the same number of steps in the cycle.
+    //
+    // Using this fact, we just find the cycle length for each XXA and then compute the least common
+    // multiple of all the cycle lengths to get the answer.
+    //
+    // Not every input has to be crafted like that, so check it: after as many steps again, the
+    // path must be back at the same XXZ node, without passing another XXZ node on the way.
+    //
+    let (steps, xxz_name) = go_to_next_xxz(network, start_name, directions, 0);
+    let (cycle_steps, cycle_xxz_name) = go_to_next_xxz(network, &xxz_name, directions, steps);
+    assume(
+        cycle_steps == steps && cycle_xxz_name == xxz_name,
+        &format!("the path from {start_name} reaches {xxz_name} every {steps} steps"),
+    );
+    steps
+}
+
+// Follow the directions from the node, starting after the first steps of the directions, until a
+// node ending in 'Z' is reached, but without stopping on the node it starts from. Returns the steps
+// and the XXZ node.
+fn go_to_next_xxz(
+    network: &HashMap<String, Node>,
+    start_name: &str,
+    directions: &str,
+    first_steps: u64,
+) -> (u64, String) {
+    // After this many steps, some node has been visited twice at the same point in the directions,
+    // so the path goes around in a cycle without a XXZ node
+    let max_steps = (network.len() * directions.len()) as u64;
+    let mut steps: u64 = 0;
+    let mut current_name: String = start_name.to_string();
+    let mut direction_index = (first_steps % directions.len() as u64) as usize;
+    loop {
+        if steps > 0 && current_name.ends_with('Z') {
+            return (steps, current_name);
+        }
+        assume(steps <= max_steps, "every path reaches a XXZ node");
+        let direction_char = directions.chars().nth(direction_index).unwrap();
+        let node = network.get(&current_name).unwrap();
+        current_name = node.next(direction_char);
+        direction_index += 1;
+        if direction_index >= directions.len() {
+            direction_index = 0;
+        }
+        steps += 1;
+    }
+}
+
+fn all_xxa(network: &HashMap<String, Node>) -> Vec<String> {
+    network
+        .keys()
+        .filter(|name| name.ends_with('A'))
+        .cloned()
+        .collect()
+}
+
+fn least_common_multiple(a: u128, b: u128) -> u128 {
+    let mut multiple = a;
+    while !multiple.is_multiple_of(b) {
+        multiple += a;
+    }
+    multiple
+}
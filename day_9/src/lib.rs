@@ -0,0 +1,54 @@
+use aoc_common::{Answer, ParseError, Solution, Solver, parse_number, solver};
+
+pub mod part_a;
+pub mod part_b;
+
+pub const SOLVER_A: Solver = solver! {
+    day: 9,
+    part: 'a',
+    description: "Sum of next numbers",
+    solution: Day9,
+};
+
+pub const SOLVER_B: Solver = solver! {
+    day: 9,
+    part: 'b',
+    description: "Sum of previous numbers",
+    solution: Day9,
+};
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<History>;
+
+    fn parse(input: &str) -> Result<Vec<History>, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(input: &Vec<History>) -> Option<Answer> {
+        Some(part_a::solve(input).into())
+    }
+
+    fn part_b(input: &Vec<History>) -> Option<Answer> {
+        Some(part_b::solve(input).into())
+    }
+}
+
+pub type History = Vec<i64>;
+
+pub fn parse(input: &str) -> Result<Vec<History>, ParseError> {
+    input
+        .lines()
+        .map(|line| line.split_whitespace().map(parse_number).collect())
+        .collect()
+}
+
+// The differences between each number and the one before it
+fn differences(numbers: &[i64]) -> Vec<i64> {
+    let mut diffs = Vec::new();
+    for i in 1..numbers.len() {
+        diffs.push(numbers[i] - numbers[i - 1]);
+    }
+    diffs
+}
@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::run(&[day_9::SOLVER_A, day_9::SOLVER_B]);
+}
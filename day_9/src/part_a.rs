@@ -0,0 +1,12 @@
+use crate::{History, differences};
+
+pub fn solve(histories: &[History]) -> i64 {
+    histories.iter().map(|numbers| next_number(numbers)).sum()
+}
+
+fn next_number(numbers: &[i64]) -> i64 {
+    if numbers.iter().all(|&x| x == 0) {
+        return 0;
+    }
+    numbers[numbers.len() - 1] + next_number(&differences(numbers))
+}
@@ -0,0 +1,18 @@
+use crate::{History, differences};
+
+pub fn solve(histories: &[History]) -> i64 {
+    histories
+        .iter()
+        .map(|numbers| prev_and_next_number(numbers).0)
+        .sum()
+}
+
+fn prev_and_next_number(numbers: &[i64]) -> (i64, i64) {
+    if numbers.iter().all(|&x| x == 0) {
+        return (0, 0);
+    }
+    let (prev_diff, next_diff) = prev_and_next_number(&differences(numbers));
+    let next = numbers[numbers.len() - 1] + next_diff;
+    let prev = numbers[0] - prev_diff;
+    (prev, next)
+}